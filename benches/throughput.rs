@@ -0,0 +1,120 @@
+//! Throughput benchmarks for the hot loops of `Tape` and `Band`.
+//!
+//! Run with `cargo bench`, optionally followed by `-- <filter>` to only run the benchmarks whose
+//! name contains the filter. Each benchmark streams a fixed number of items through a ribbon and
+//! reports the best time per item over several runs.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use ribbon::{Band, Ribbon, Tape};
+
+const ITEMS: usize = 10_000_000;
+const RUNS: usize = 10;
+
+fn bench(name: &str, mut f: impl FnMut() -> u64) {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    if filter.is_some_and(|filter| !name.contains(&filter)) {
+        return;
+    }
+
+    let mut best = Duration::MAX;
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        black_box(f());
+        best = best.min(start.elapsed());
+    }
+
+    let per_item = best.as_secs_f64() * 1e9 / ITEMS as f64;
+    println!("{name:<24} {per_item:>8.3} ns/item");
+}
+
+fn source<T: From<u8>>() -> impl Iterator<Item = T> {
+    (0..ITEMS).map(|i| T::from(i as u8))
+}
+
+fn band_progress<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    let mut band: Band<64, _> = Band::new(source::<T>());
+    band.expand_n(64);
+
+    let mut sum = 0;
+    while let Some(item) = band.progress() {
+        sum += item.into();
+    }
+
+    sum
+}
+
+fn band_expand_n<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    let mut band: Band<64, _> = Band::new(source::<T>());
+
+    let mut sum = 0;
+    while band.expand_n(16) {
+        sum += band.peek_back().map_or(0, |item| (*item).into());
+    }
+
+    sum
+}
+
+fn tape_progress<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    let mut tape = Tape::new(source::<T>());
+    tape.expand_n(64);
+
+    let mut sum = 0;
+    while let Some(item) = tape.progress() {
+        sum += item.into();
+    }
+
+    sum
+}
+
+fn tape_progress_large<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    let mut tape = Tape::new(source::<T>());
+    tape.expand_n(4096);
+
+    let mut sum = 0;
+    while let Some(item) = tape.progress() {
+        sum += item.into();
+    }
+
+    sum
+}
+
+fn tape_next<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    Tape::new(source::<T>()).map(Into::into).sum()
+}
+
+fn band_next<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    Band::<64, _>::new(source::<T>()).map(Into::into).sum()
+}
+
+fn tape_expand_n<T: Copy + From<u8> + Into<u64>>() -> u64 {
+    let mut tape = Tape::new(source::<T>());
+
+    let mut sum = 0;
+    while tape.expand_n(16) {
+        sum += tape.drain_front(16).map(Into::into).sum::<u64>();
+    }
+
+    sum
+}
+
+fn main() {
+    bench("band/progress/u8", band_progress::<u8>);
+    bench("band/progress/u32", band_progress::<u32>);
+    bench("band/expand_n/u8", band_expand_n::<u8>);
+    bench("band/expand_n/u32", band_expand_n::<u32>);
+    bench("band/next/u8", band_next::<u8>);
+    bench("band/next/u32", band_next::<u32>);
+    bench("tape/next/u8", tape_next::<u8>);
+    bench("tape/next/u32", tape_next::<u32>);
+    bench("tape/progress/u8", tape_progress::<u8>);
+    bench("tape/progress/u32", tape_progress::<u32>);
+    bench("tape/progress_large/u8", tape_progress_large::<u8>);
+    bench("tape/progress_large/u32", tape_progress_large::<u32>);
+    bench("tape/expand_n/u8", tape_expand_n::<u8>);
+    bench("tape/expand_n/u32", tape_expand_n::<u32>);
+}
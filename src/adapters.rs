@@ -0,0 +1,387 @@
+//! Iterator adapters built on top of types that implement the [`Ribbon`] trait.
+//!
+//! [`Ribbon`]: crate::Ribbon
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "alloc")]
+use crate::Tape;
+use crate::{Band, Ribbon};
+
+/// Iterator over overlapping windows of `K` consecutive items of the wrapped iterator, backed by a
+/// [`Band`].
+///
+/// Created with [`Enroll::windows`].
+///
+/// [`Band`]: crate::Band
+/// [`Enroll::windows`]: crate::Enroll::windows
+pub struct Windows<const K: usize, I>
+where
+    I: Iterator,
+{
+    band: Band<K, I>,
+}
+
+impl<const K: usize, I> Windows<K, I>
+where
+    I: Iterator,
+{
+    /// Creates a new `Windows` over the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is zero.
+    pub fn new(iter: I) -> Windows<K, I> {
+        assert!(K > 0, "window size must be non-zero");
+
+        Windows {
+            band: Band::new(iter),
+        }
+    }
+}
+
+impl<const K: usize, I> Iterator for Windows<K, I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.band.len() == K {
+            self.band.progress()?;
+        } else if !self.band.fill() {
+            return None;
+        }
+
+        Some(core::array::from_fn(|i| self.band[i].clone()))
+    }
+}
+
+impl<const K: usize, I> fmt::Debug for Windows<K, I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows").field("band", &self.band).finish()
+    }
+}
+
+impl<const K: usize, I> Clone for Windows<K, I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            band: self.band.clone(),
+        }
+    }
+}
+
+/// Iterator over non-overlapping chunks of `size` consecutive items of the wrapped iterator, backed
+/// by a [`Tape`]. The last chunk is shorter if the iterator does not produce a multiple of `size`
+/// items.
+///
+/// Created with [`Enroll::chunks`].
+///
+/// [`Tape`]: crate::Tape
+/// [`Enroll::chunks`]: crate::Enroll::chunks
+#[cfg(feature = "alloc")]
+pub struct Chunks<I>
+where
+    I: Iterator,
+{
+    tape: Tape<I>,
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Chunks<I>
+where
+    I: Iterator,
+{
+    /// Creates a new `Chunks` over the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(iter: I, size: usize) -> Chunks<I> {
+        assert!(size > 0, "chunk size must be non-zero");
+
+        Chunks {
+            tape: Tape::with_capacity(iter, size),
+            size,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for Chunks<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.tape.expand_n(self.size) {
+            return None;
+        }
+
+        Some(self.tape.drain_front(self.size).collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> fmt::Debug for Chunks<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chunks")
+            .field("tape", &self.tape)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Clone for Chunks<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tape: self.tape.clone(),
+            size: self.size,
+        }
+    }
+}
+
+/// Iterator that advances two [`Ribbon`]s in lockstep, yielding pairs of their items. Each step
+/// takes the head of both `Ribbon`s, expanding them if they are empty, and stops as soon as either
+/// of them cannot produce an item. In that case, the item of the other `Ribbon` is not taken.
+///
+/// Created with [`Ribbon::zip_ribbon`].
+///
+/// [`Ribbon`]: crate::Ribbon
+/// [`Ribbon::zip_ribbon`]: crate::Ribbon::zip_ribbon
+pub struct ZipRibbon<A, B, T, U> {
+    a: A,
+    b: B,
+    items: PhantomData<fn() -> (T, U)>,
+}
+
+impl<A, B, T, U> ZipRibbon<A, B, T, U>
+where
+    A: Ribbon<T>,
+    B: Ribbon<U>,
+{
+    /// Creates a new `ZipRibbon` over the given `Ribbon`s.
+    pub fn new(a: A, b: B) -> ZipRibbon<A, B, T, U> {
+        ZipRibbon {
+            a,
+            b,
+            items: PhantomData,
+        }
+    }
+
+    /// Consumes the `ZipRibbon` and returns the wrapped `Ribbon`s.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A, B, T, U> Iterator for ZipRibbon<A, B, T, U>
+where
+    A: Ribbon<T>,
+    B: Ribbon<U>,
+{
+    type Item = (T, U);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.a.is_empty() && !self.a.expand() {
+            return None;
+        }
+
+        if self.b.is_empty() && !self.b.expand() {
+            return None;
+        }
+
+        Some((self.a.pop_front()?, self.b.pop_front()?))
+    }
+}
+
+impl<A, B, T, U> fmt::Debug for ZipRibbon<A, B, T, U>
+where
+    A: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZipRibbon")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<A, B, T, U> Clone for ZipRibbon<A, B, T, U>
+where
+    A: Clone,
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            items: PhantomData,
+        }
+    }
+}
+
+/// Iterator that removes a range of items from a [`Ribbon`], yielding them in order. Items that
+/// were not yielded are removed when the iterator is dropped.
+///
+/// Created with [`Ribbon::drain`].
+///
+/// [`Ribbon`]: crate::Ribbon
+/// [`Ribbon::drain`]: crate::Ribbon::drain
+pub(crate) struct Drain<'a, R, T>
+where
+    R: Ribbon<T>,
+{
+    ribbon: &'a mut R,
+    start: usize,
+    remaining: usize,
+    items: PhantomData<fn() -> T>,
+}
+
+impl<'a, R, T> Drain<'a, R, T>
+where
+    R: Ribbon<T>,
+{
+    /// Creates a new `Drain` removing `len` items from the given `Ribbon`, starting at index
+    /// `start`. The range must be in bounds.
+    pub(crate) fn new(ribbon: &'a mut R, start: usize, len: usize) -> Drain<'a, R, T> {
+        debug_assert!(start + len <= ribbon.len());
+
+        Drain {
+            ribbon,
+            start,
+            remaining: len,
+            items: PhantomData,
+        }
+    }
+}
+
+impl<R, T> Iterator for Drain<'_, R, T>
+where
+    R: Ribbon<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.ribbon.remove_at(self.start)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<R, T> Drop for Drain<'_, R, T>
+where
+    R: Ribbon<T>,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Band, Enroll, Ribbon};
+
+    #[test]
+    fn windows() {
+        let mut windows = (0..5).windows::<3>();
+
+        assert_eq!(windows.next(), Some([0, 1, 2]));
+        assert_eq!(windows.next(), Some([1, 2, 3]));
+        assert_eq!(windows.next(), Some([2, 3, 4]));
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn windows_too_short() {
+        assert_eq!((0..2).windows::<3>().next(), None);
+        assert_eq!((0..3).windows::<3>().count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chunks() {
+        let mut chunks = (0..7).chunks(3);
+
+        assert_eq!(chunks.next(), Some(vec![0, 1, 2]));
+        assert_eq!(chunks.next(), Some(vec![3, 4, 5]));
+        assert_eq!(chunks.next(), Some(vec![6]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+
+        assert_eq!((0..6).chunks(3).count(), 2);
+        assert_eq!((0..0).chunks(3).next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn chunks_of_zero() {
+        (0..5).chunks(0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn zips_ribbons() {
+        use crate::Tape;
+
+        let pairs: Vec<_> = Tape::new(0..5).zip_ribbon(Tape::new(10..20)).collect();
+        assert_eq!(pairs, vec![(0, 10), (1, 11), (2, 12), (3, 13), (4, 14)]);
+
+        // the item of the longer ribbon is not taken when the shorter one is exhausted
+        let mut zip = Tape::new(0..5).zip_ribbon(Band::<2, _>::new(10..12));
+        assert_eq!(zip.by_ref().count(), 2);
+
+        let (mut tape, band) = zip.into_inner();
+        assert_eq!(tape.pop_front(), Some(2));
+        assert!(band.is_empty());
+    }
+
+    #[test]
+    fn zips_buffered_ribbons() {
+        let mut a: Band<3, _> = Band::new(0..5);
+        let mut b: Band<3, _> = Band::new(10..20);
+        a.expand_n(3);
+        b.expand();
+
+        let pairs: Vec<_> = a.zip_ribbon(b).collect();
+        assert_eq!(pairs, vec![(0, 10), (1, 11), (2, 12), (3, 13), (4, 14)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_of_zero() {
+        (0..5).windows::<0>();
+    }
+}
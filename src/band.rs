@@ -1,57 +1,570 @@
 //! Implementation of statically sized data structures that implement the [`Ribbon`] trait.
 
-use std::iter::Peekable;
-
-use crate::{ribbon, Ribbon};
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{Chain, Take},
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut, Range},
+    ptr, slice,
+};
+
+#[cfg(feature = "alloc")]
+use crate::Tape;
+use crate::{ribbon, source::Source, Ribbon, RibbonView};
 
 /// A fix-sized [`Ribbon`] backed up by an array of `N` elements. It cannot grow over the given
 /// fixed length, and instead drops and/or returns items if no space is available at the given
 /// moment.
 ///
+/// A `Band` must have a non-zero capacity. Creating a `Band<0, _>` in any way, be it through
+/// [`Band::new`], [`Default`], [`From`] or [`Enroll::band`], panics.
+///
 /// [`Ribbon`]: crate::Ribbon
-#[derive(Debug)]
+/// [`Enroll::band`]: crate::Enroll::band
 pub struct Band<const LEN: usize, I>
 where
     I: Iterator,
 {
-    iter: Peekable<I>,
-    tape: [Option<I::Item>; LEN],
+    iter: Source<I>,
+    /// Slots at logical indices `0..len`, starting at `head` and wrapping around, are initialized.
+    /// All other slots are uninitialized.
+    tape: [MaybeUninit<I::Item>; LEN],
     head: usize,
     len: usize,
+    overflow: Overflow,
+}
+
+/// A [`Band`] that holds at most 8 items.
+///
+/// Together with [`Enroll::band`], which infers the capacity from the annotated type, this avoids
+/// spelling out the capacity with a turbofish.
+///
+/// # Example
+///
+/// ```rust
+/// use ribbon::{Band8, Enroll, Ribbon};
+///
+/// let mut band: Band8<_> = (0..20).band();
+///
+/// band.expand_n(10);
+/// assert_eq!(band.len(), 8);
+/// assert_eq!(band.capacity(), 8);
+/// ```
+///
+/// [`Enroll::band`]: crate::Enroll::band
+pub type Band8<I> = Band<8, I>;
+
+/// A [`Band`] that holds at most 16 items. See [`Band8`].
+pub type Band16<I> = Band<16, I>;
+
+/// A [`Band`] that holds at most 32 items. See [`Band8`].
+pub type Band32<I> = Band<32, I>;
+
+/// Policy that determines what happens when a full [`Band`] is expanded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// The head of the `Band` is dropped to make room for the new item. This is the default.
+    #[default]
+    DropOldest,
+    /// The `Band` is not expanded, and the iterator is not polled.
+    Reject,
 }
 
 impl<const LEN: usize, I> Band<LEN, I>
 where
     I: Iterator,
 {
-    /// Creates a new `Tape` from the given iterator.
+    /// Creates a new `Band` from the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LEN` is zero, since a `Band` without capacity cannot hold any items.
     pub fn new(iter: I) -> Band<LEN, I> {
-        let tape = [0; LEN].map(|_| None);
+        Band::from_source(Source::new(iter))
+    }
+
+    /// Creates a new empty `Band` that continues from the given source, keeping its peeked item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LEN` is zero.
+    pub(crate) fn from_source(iter: Source<I>) -> Band<LEN, I> {
+        assert!(LEN > 0, "`Band` must have a non-zero capacity");
 
         Band {
-            iter: iter.peekable(),
-            tape,
+            iter,
+            tape: [const { MaybeUninit::uninit() }; LEN],
             head: 0,
             len: 0,
+            overflow: Overflow::DropOldest,
+        }
+    }
+
+    /// Creates a new bounded `Band` from the given iterator, that refuses to expand once it is
+    /// full, rather than dropping its head. See [`Overflow::Reject`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LEN` is zero, since a `Band` without capacity cannot hold any items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<2, _> = Band::new_bounded(0..10);
+    ///
+    /// assert!(band.expand_n(2));
+    /// assert!(!band.expand());
+    /// assert_eq!(band.peek_front(), Some(&0));
+    /// ```
+    pub fn new_bounded(iter: I) -> Band<LEN, I> {
+        let mut band = Band::new(iter);
+        band.overflow = Overflow::Reject;
+        band
+    }
+
+    /// Returns the policy that determines what happens when the full `Band` is expanded.
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Sets the policy that determines what happens when the full `Band` is expanded.
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
+    /// Consumes the `Band` and returns the wrapped iterator. The returned iterator continues from
+    /// where the `Band` left off.
+    ///
+    /// Items currently found on the `Band` are dropped. This includes the item that was pulled from
+    /// the iterator and rejected by the last call to [`expand_while`], if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    /// band.expand_n(3);
+    ///
+    /// let mut iter = band.into_inner();
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    ///
+    /// [`expand_while`]: crate::Ribbon::expand_while
+    pub fn into_inner(self) -> I {
+        let mut band = ManuallyDrop::new(self);
+        band.clear();
+
+        // SAFETY: the items were dropped by `clear`, and `band` is never dropped, so the iterator is
+        // moved out exactly once.
+        let iter = unsafe { ptr::read(&band.iter) };
+        iter.into_parts().1
+    }
+
+    /// Consumes the `Band` and returns an iterator over the items currently found on it, from head
+    /// to tail. No further items are pulled from the wrapped iterator.
+    ///
+    /// In contrast, iterating over the `Band` itself (e.g. `for item in band {}`) yields the items
+    /// found on the `Band` first, and then continues pulling items from the wrapped iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    /// band.expand_n(5);
+    ///
+    /// let items: Vec<_> = band.into_buffered_iter().collect();
+    /// assert_eq!(items, vec![2, 3, 4]);
+    /// ```
+    pub fn into_buffered_iter(mut self) -> impl Iterator<Item = I::Item> {
+        core::iter::from_fn(move || self.pop_front())
+    }
+
+    /// Transforms the `Band` into one that expands by at most `n` more items in total. Items
+    /// currently found on the `Band` are kept and do not count towards the limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<4, _> = Band::new(0..10);
+    /// band.expand_n(2);
+    ///
+    /// let mut band = band.take(3);
+    /// assert!(band.expand_n(5));
+    /// assert!(!band.expand());
+    /// assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn take(self, n: usize) -> Band<LEN, Take<I>> {
+        let band = ManuallyDrop::new(self);
+
+        // SAFETY: `band` is never dropped, so the iterator and the items are moved out exactly once.
+        let (iter, tape) = unsafe { (ptr::read(&band.iter), ptr::read(&band.tape)) };
+
+        Band {
+            iter: iter.take(n),
+            tape,
+            head: band.head,
+            len: band.len,
+            overflow: band.overflow,
+        }
+    }
+
+    /// Moves the wrapped iterator and the items currently found on the `Band` into a new `Band`
+    /// with capacity `M`. The new `Band` keeps the [`Overflow`] policy, and continues from where
+    /// this one left off.
+    ///
+    /// Items are moved from head to tail, as if the new `Band` was expanded with them. If they do
+    /// not fit, the policy decides which ones are dropped: [`Overflow::DropOldest`] keeps the `M`
+    /// items closest to the tail, and [`Overflow::Reject`] keeps the `M` items closest to the head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero, since a `Band` without capacity cannot hold any items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<2, _> = Band::new(0..10);
+    /// band.expand_n(2);
+    ///
+    /// let mut band: Band<4, _> = band.resize();
+    /// assert!(band.expand_n(2));
+    /// assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &3]);
+    /// ```
+    pub fn resize<const M: usize>(self) -> Band<M, I> {
+        let mut this = ManuallyDrop::new(self);
+
+        // SAFETY: `this` is never dropped, so the iterator is moved out exactly once. The items are
+        // popped from `this` below, so none of them is leaked.
+        let iter = unsafe { ptr::read(&this.iter) };
+
+        let mut band = Band::from_source(iter);
+        band.overflow = this.overflow;
+
+        while let Some(item) = this.pop_front() {
+            let len = band.len();
+            drop(band.insert_at(len, item));
+        }
+
+        band
+    }
+
+    /// Moves the wrapped iterator and the items currently found on the `Band` into a new [`Tape`],
+    /// from head to tail. The `Tape` continues from where the `Band` left off.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<2, _> = Band::new(0..10);
+    /// band.expand_n(3);
+    ///
+    /// let mut tape = band.into_tape();
+    /// assert!(tape.expand_n(2));
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    ///
+    /// [`Tape`]: crate::Tape
+    #[cfg(feature = "alloc")]
+    pub fn into_tape(self) -> Tape<I> {
+        let mut this = ManuallyDrop::new(self);
+
+        // SAFETY: `this` is never dropped, so the iterator is moved out exactly once. The items are
+        // popped from `this` below, so none of them is leaked.
+        let iter = unsafe { ptr::read(&this.iter) };
+
+        let mut buffer = VecDeque::with_capacity(this.len);
+        buffer.extend(core::iter::from_fn(|| this.pop_front()));
+
+        Tape::from_source(iter, buffer)
+    }
+
+    /// Expands the `Band` until it is full, or the iterator stops producing values. Returns `true`
+    /// if the `Band` is full afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    ///
+    /// assert!(band.fill());
+    /// assert_eq!(band.len(), 3);
+    /// assert_eq!(band.peek_front(), Some(&0));
+    /// ```
+    pub fn fill(&mut self) -> bool {
+        while !self.is_full() && self.expand() {}
+
+        self.is_full()
+    }
+
+    /// Expands the `Band` if it is not full, or streams the iterator forward through it otherwise.
+    /// Returns the head of the `Band` removed in the process, so `None` is returned while the
+    /// `Band` is not full.
+    ///
+    /// This keeps the `Band` as full as possible, as opposed to [`progress`], which removes the
+    /// head even if the `Band` is not full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<2, _> = Band::new(0..10);
+    ///
+    /// assert_eq!(band.progress_or_expand(), None);
+    /// assert_eq!(band.progress_or_expand(), None);
+    /// assert_eq!(band.len(), 2);
+    ///
+    /// assert_eq!(band.progress_or_expand(), Some(0));
+    /// assert_eq!(band.progress_or_expand(), Some(1));
+    /// assert_eq!(band.len(), 2);
+    /// ```
+    ///
+    /// [`progress`]: crate::Ribbon::progress
+    pub fn progress_or_expand(&mut self) -> Option<I::Item> {
+        if self.is_full() {
+            self.progress()
+        } else {
+            self.expand();
+            None
+        }
+    }
+
+    /// Expands the `Band` by consuming the next available item and appending it to the end, like
+    /// [`expand`]. Returns the head of the `Band` if it was dropped to make room for the new item,
+    /// which happens exactly when the `Band` was full. Returns `None` otherwise, as well as when the
+    /// `Band` was not expanded at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<2, _> = Band::new(0..10);
+    ///
+    /// assert_eq!(band.expand_returning(), None);
+    /// assert_eq!(band.expand_returning(), None);
+    /// assert_eq!(band.expand_returning(), Some(0));
+    /// assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    ///
+    /// [`expand`]: crate::Ribbon::expand
+    pub fn expand_returning(&mut self) -> Option<I::Item> {
+        if self.overflow == Overflow::Reject && self.is_full() {
+            return None;
+        }
+
+        let item = self.iter.next()?;
+        let evicted = if self.is_full() { self.slide() } else { None };
+        self.push_back_unchecked(item);
+
+        evicted
+    }
+
+    /// Advances the `Band` as a sliding window over the iterator. If the `Band` is not full, it is
+    /// filled with as many items as possible and `None` is returned. Otherwise, the iterator is
+    /// streamed forward through the `Band` and its evicted head is returned.
+    ///
+    /// This differs from [`progress`], which evicts the head on every call regardless of how many
+    /// items the `Band` holds, so a fresh `Band` passes items through one by one. It also differs
+    /// from [`progress_or_expand`], which expands a `Band` that is not full by a single item per
+    /// call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    ///
+    /// assert_eq!(band.advance(), None);
+    /// assert_eq!(band.len(), 3);
+    ///
+    /// assert_eq!(band.advance(), Some(0));
+    /// assert_eq!(band.advance(), Some(1));
+    /// assert_eq!(band.peek_front(), Some(&2));
+    /// ```
+    ///
+    /// [`progress`]: crate::Ribbon::progress
+    /// [`progress_or_expand`]: Band::progress_or_expand
+    pub fn advance(&mut self) -> Option<I::Item> {
+        if self.is_full() {
+            self.progress()
+        } else {
+            self.fill();
+            None
+        }
+    }
+
+    /// Returns a reference to the next item of the underlying iterator, without expanding the
+    /// `Band`. The item is held in an internal one-slot lookahead buffer, and is appended by the
+    /// next expansion.
+    ///
+    /// The same buffer keeps the item rejected by predicate-based expansions such as
+    /// [`expand_while`], so that it is not lost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    /// band.expand_while(|item| *item < 2);
+    ///
+    /// assert_eq!(band.peek_source(), Some(&2));
+    /// assert!(band.expand());
+    /// assert_eq!(band.peek_back(), Some(&2));
+    /// ```
+    ///
+    /// [`expand_while`]: crate::Ribbon::expand_while
+    pub fn peek_source(&mut self) -> Option<&I::Item> {
+        self.iter.peek()
+    }
+
+    /// Returns a pair of slices which contain, in order, the items currently found on the `Band`.
+    /// The first slice covers the items stored from the head up to the end of the inner array, and
+    /// the second one covers the items that wrapped around to the start of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<4, _> = Band::new(0..10);
+    /// band.expand_n(6);
+    ///
+    /// let (first, second) = band.as_slices();
+    /// assert_eq!(first, &[2, 3]);
+    /// assert_eq!(second, &[4, 5]);
+    /// ```
+    pub fn as_slices(&self) -> (&[I::Item], &[I::Item]) {
+        let (first, second) = self.split_occupied();
+        let (first, second) = (&self.tape[first.0..first.1], &self.tape[second.0..second.1]);
+
+        // SAFETY: occupied slots are initialized, and `MaybeUninit<T>` has the same layout as `T`.
+        unsafe {
+            (
+                &*(first as *const [MaybeUninit<I::Item>] as *const [I::Item]),
+                &*(second as *const [MaybeUninit<I::Item>] as *const [I::Item]),
+            )
+        }
+    }
+
+    /// Returns a read-only view into the given range of the items currently found on the `Band`,
+    /// that can be passed around without moving the `Band`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<4, _> = Band::new(0..10);
+    /// band.expand_n(6);
+    ///
+    /// let view = band.view(1..3);
+    /// assert_eq!(view.iter().collect::<Vec<_>>(), vec![&3, &4]);
+    /// ```
+    pub fn view(&self, range: Range<usize>) -> RibbonView<'_, I::Item> {
+        let (first, second) = self.as_slices();
+        RibbonView::new(first, second, range)
+    }
+
+    /// Returns a pair of mutable slices which contain, in order, the items currently found on the
+    /// `Band`. See [`as_slices`] for details.
+    ///
+    /// [`as_slices`]: Band::as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [I::Item], &mut [I::Item]) {
+        let (first, second) = self.split_occupied();
+        let (wrapped, head) = self.tape.split_at_mut(first.0);
+        let (first, second) = (
+            &mut head[..first.1 - first.0],
+            &mut wrapped[second.0..second.1],
+        );
+
+        // SAFETY: occupied slots are initialized, and `MaybeUninit<T>` has the same layout as `T`.
+        unsafe {
+            (
+                &mut *(first as *mut [MaybeUninit<I::Item>] as *mut [I::Item]),
+                &mut *(second as *mut [MaybeUninit<I::Item>] as *mut [I::Item]),
+            )
+        }
+    }
+
+    /// Returns the ranges of the inner array, as `(start, end)` pairs, that hold the items of the
+    /// `Band` from head up to the end of the array, and from the start of the array respectively.
+    fn split_occupied(&self) -> ((usize, usize), (usize, usize)) {
+        let end = self.head + self.len;
+
+        if end > LEN {
+            ((self.head, LEN), (0, end - LEN))
+        } else {
+            ((self.head, end), (0, 0))
         }
     }
 
+    /// Converts the logical index into the index of the inner array.
+    fn physical(&self, index: usize) -> usize {
+        (self.head + index) % LEN
+    }
+
     /// Shifts all items by 1, returning the head of the `Band`.
     ///
     /// Shifting is a misnomer, and runs in `O(1)`. Rather than shifting elements, the indices
     /// pointing to the first and last element are shifted.
     fn slide(&mut self) -> Option<I::Item> {
-        let first = self.tape[self.head].take()?;
+        if self.is_empty() {
+            return None;
+        }
+
+        // SAFETY: `Band` is not empty, so the head slot is initialized. It is treated as
+        // uninitialized from now on, since the head is moved past it.
+        let first = unsafe { self.tape[self.head].assume_init_read() };
 
         self.incr_head();
-        self.len = self.len.saturating_sub(1);
+        self.len -= 1;
+        self.debug_assert_invariants();
 
         Some(first)
     }
 
-    /// Checks if the `Band` is at full capacity.
-    fn is_full(&self) -> bool {
-        self.len() == LEN
+    /// Appends the item to the tail of the `Band`. The `Band` must not be full.
+    fn push_back_unchecked(&mut self, item: I::Item) {
+        debug_assert!(!self.is_full());
+
+        self.len += 1;
+
+        let tail = self.tail();
+        self.tape[tail].write(item);
+        self.debug_assert_invariants();
+    }
+
+    /// Prepends the item to the head of the `Band`. The `Band` must not be full.
+    fn push_front_unchecked(&mut self, item: I::Item) {
+        debug_assert!(!self.is_full());
+
+        self.head = (self.head + LEN - 1) % LEN;
+        self.len += 1;
+        self.tape[self.head].write(item);
+        self.debug_assert_invariants();
     }
 
     /// Moves the head index by 1, wrapping around to the start of inner array when longer than
@@ -60,9 +573,21 @@ where
         self.head = (self.head + 1) % LEN;
     }
 
-    /// Calculates the tail index based on head index and length of the `Band`.
+    /// Checks in debug builds that the length of the `Band` does not exceed its capacity and that
+    /// the head is within the bounds of the inner array. Whether exactly the `len` slots starting
+    /// at `head` are initialized cannot be observed on [`MaybeUninit`] slots, so it is not checked.
+    #[inline]
+    fn debug_assert_invariants(&self) {
+        debug_assert!(self.len <= LEN, "length exceeds the capacity of the `Band`");
+        debug_assert!(self.head < LEN, "head is out of bounds");
+    }
+
+    /// Calculates the tail index based on head index and length of the `Band`. The `Band` must not
+    /// be empty, since an empty `Band` has no tail slot.
     fn tail(&self) -> usize {
-        (self.head + self.len.saturating_sub(1)) % LEN
+        debug_assert!(self.len > 0);
+
+        (self.head + self.len - 1) % LEN
     }
 }
 
@@ -70,49 +595,116 @@ impl<const LEN: usize, I> ribbon::Ribbon<I::Item> for Band<LEN, I>
 where
     I: Iterator,
 {
+    /// Streams the iterator forward through the `Band` without expanding it. Underlying iterator
+    /// is polled for the next item, which is appended to the tail. The head of the `Band` is
+    /// removed and returned.
+    ///
+    /// The head is removed even if the `Band` is not full, so the length of the `Band` stays the
+    /// same. On an empty `Band`, the item is appended and `None` is returned. Use
+    /// [`progress_or_expand`] or [`advance`] to expand the `Band` until it is full instead.
+    ///
+    /// Is a no-op if iterator stops producing values. In that case `None` is returned.
+    ///
+    /// [`progress_or_expand`]: Band::progress_or_expand
+    /// [`advance`]: Band::advance
     fn progress(&mut self) -> Option<I::Item> {
         let next = self.iter.next()?; // do nothing if iterator does not produce
 
         let head = self.slide();
-        self.len += 1;
+        self.push_back_unchecked(next);
+        self.debug_assert_invariants();
 
-        self.tape[self.tail()] = Some(next);
         head
     }
 
     /// Expands the `Band` by consuming the next available item and appending it to the end.
-    /// Drops the first element if the `Band` is already at full capacity.
+    /// If the `Band` is already at full capacity, the [`Overflow`] policy applies: either the first
+    /// element is dropped, or the `Band` is not expanded and `false` is returned.
+    ///
+    /// Returns `false` without touching the buffered items if the iterator is exhausted.
     fn expand(&mut self) -> bool {
+        if self.overflow == Overflow::Reject && self.is_full() {
+            return false;
+        }
+
+        let Some(item) = self.iter.next() else {
+            return false;
+        };
+
         if self.is_full() {
             self.slide();
         }
 
-        if let Some(item) = self.iter.next() {
-            self.tape[self.len] = Some(item);
-            self.len += 1;
-            return true;
+        self.push_back_unchecked(item);
+        self.debug_assert_invariants();
+        true
+    }
+
+    fn expand_step_by(&mut self, n: usize, step: usize) -> bool {
+        assert!(step != 0, "step must be non-zero");
+
+        let mut expanded = false;
+
+        for i in 0..n {
+            // do not skip items that could not be appended anyway
+            if self.overflow == Overflow::Reject && self.is_full() {
+                break;
+            }
+
+            if i > 0 {
+                self.iter.discard(step - 1);
+            }
+
+            if !self.expand() {
+                break;
+            }
+
+            expanded = true;
         }
 
-        false
+        expanded
     }
 
-    fn expand_while<F>(&mut self, f: F) -> bool
+    fn expand_while_indexed<F>(&mut self, f: F) -> bool
     where
-        F: Fn(&I::Item) -> bool,
+        F: Fn(usize, &I::Item) -> bool,
     {
-        let mut expanded = false;
+        let mut count = 0;
+
         loop {
             match self.iter.peek() {
-                Some(item) if f(item) => {
-                    expanded = true;
+                Some(item) if f(count, item) => {
+                    if !self.expand() {
+                        break;
+                    }
 
-                    self.expand();
+                    count += 1;
                 }
                 _ => break,
             }
         }
 
-        expanded
+        count > 0
+    }
+
+    fn expand_while_peek<F>(&mut self, f: F) -> (bool, Option<&I::Item>)
+    where
+        F: Fn(&I::Item) -> bool,
+    {
+        let expanded = self.expand_while(f);
+        (expanded, self.iter.peek())
+    }
+
+    fn source_exhausted(&mut self) -> bool {
+        self.iter.peek().is_none()
+    }
+
+    fn seal(&mut self) {
+        self.iter.seal();
+    }
+
+    fn expand_take(&mut self, n: usize) {
+        self.iter.limit(n);
     }
 
     fn pop_front(&mut self) -> Option<I::Item> {
@@ -128,89 +720,600 @@ where
     }
 
     fn pop_back(&mut self) -> Option<I::Item> {
-        let back = self.tape[self.tail()].take()?;
+        if self.is_empty() {
+            return None;
+        }
+
+        // SAFETY: `Band` is not empty, so the tail slot is initialized. It is treated as
+        // uninitialized from now on, since the length is decreased.
+        let back = unsafe { self.tape[self.tail()].assume_init_read() };
         self.len -= 1;
-        Some(back)
-    }
+        self.debug_assert_invariants();
 
-    fn peek_back(&self) -> Option<&I::Item> {
-        self.peek_at(self.tail())
+        Some(back)
     }
 
     fn peek_back_mut(&mut self) -> Option<&mut I::Item> {
-        self.peek_at_mut(self.tail())
+        self.peek_at_mut(self.len.checked_sub(1)?)
     }
 
     fn peek_at(&self, index: usize) -> Option<&I::Item> {
-        if index >= LEN {
+        if index >= self.len {
             return None;
         }
 
-        let idx = (self.head + index) % LEN;
-        self.tape.get(idx)?.as_ref()
+        // SAFETY: index is smaller than length, so the slot is initialized.
+        Some(unsafe { self.tape[self.physical(index)].assume_init_ref() })
     }
 
     fn peek_at_mut(&mut self, index: usize) -> Option<&mut I::Item> {
-        if index >= LEN {
+        if index >= self.len {
             return None;
         }
 
-        let idx = (self.head + index) % LEN;
-        self.tape.get_mut(idx)?.as_mut()
+        let idx = self.physical(index);
+
+        // SAFETY: index is smaller than length, so the slot is initialized.
+        Some(unsafe { self.tape[idx].assume_init_mut() })
     }
 
-    fn len(&self) -> usize {
-        self.len
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a I::Item>
+    where
+        I::Item: 'a,
+    {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second.iter())
     }
-}
 
-impl<const LEN: usize, I> Iterator for Band<LEN, I>
-where
-    I: Iterator,
-{
-    type Item = I::Item;
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut I::Item>
+    where
+        I::Item: 'a,
+    {
+        let (first, second) = self.as_mut_slices();
+        first.iter_mut().chain(second.iter_mut())
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.is_empty() {
-            self.expand_n(LEN);
+    fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop_back();
         }
-
-        self.pop_front()
     }
-}
 
-impl<const LEN: usize, I> From<I> for Band<LEN, I>
-where
-    I: Iterator,
-{
-    fn from(value: I) -> Self {
-        Band::new(value)
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&I::Item) -> bool,
+    {
+        // items are treated as uninitialized while being moved, so that a panic in `f` leaks them
+        // rather than dropping them twice
+        let len = self.len;
+        self.len = 0;
+
+        let mut retained = 0;
+        for index in 0..len {
+            // SAFETY: index is smaller than the original length, so the slot is initialized. It is
+            // either overwritten or treated as uninitialized from now on.
+            let item = unsafe { self.tape[self.physical(index)].assume_init_read() };
+
+            if f(&item) {
+                let idx = self.physical(retained);
+                self.tape[idx].write(item);
+                retained += 1;
+            }
+        }
+
+        self.len = retained;
+        self.debug_assert_invariants();
     }
-}
 
-impl<const LEN: usize, I> Clone for Band<LEN, I>
-where
-    I: Iterator + Clone,
-    I::Item: Clone,
-{
-    fn clone(&self) -> Self {
-        Self {
-            iter: self.iter.clone(),
-            tape: self.tape.clone(),
-            head: self.head,
-            len: self.len,
+    fn dedup_buffered(&mut self)
+    where
+        I::Item: PartialEq,
+    {
+        // items are treated as uninitialized while being moved, so that a panic in `eq` leaks them
+        // rather than dropping them twice
+        let len = self.len;
+        self.len = 0;
+
+        let mut retained = 0;
+        for index in 0..len {
+            // SAFETY: index is smaller than the original length, so the slot is initialized. It is
+            // either overwritten or treated as uninitialized from now on.
+            let item = unsafe { self.tape[self.physical(index)].assume_init_read() };
+
+            // SAFETY: slots up to `retained` were written by previous iterations.
+            let duplicate = retained > 0
+                && unsafe { self.tape[self.physical(retained - 1)].assume_init_ref() } == &item;
+
+            if !duplicate {
+                let idx = self.physical(retained);
+                self.tape[idx].write(item);
+                retained += 1;
+            }
         }
+
+        self.len = retained;
+        self.debug_assert_invariants();
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::Band;
-    use crate::{ribbon::Ribbon, Enroll};
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len;
+        assert!(
+            i < len,
+            "index out of bounds: the len is {len} but the index is {i}"
+        );
+        assert!(
+            j < len,
+            "index out of bounds: the len is {len} but the index is {j}"
+        );
+
+        let (i, j) = (self.physical(i), self.physical(j));
+        self.tape.swap(i, j);
+    }
 
-    #[test]
-    fn expands() {
-        let mut band: Band<5, _> = Band::new(0u32..10u32);
+    /// Inserts the value at the given index of the `Band`, shifting all items after it towards the
+    /// tail.
+    ///
+    /// If the `Band` is full, the [`Overflow`] policy applies. With [`Overflow::DropOldest`], the
+    /// value is inserted and the head of the `Band` is removed and returned. If the value is
+    /// inserted at the head, it is therefore returned right away. With [`Overflow::Reject`], the
+    /// `Band` is left untouched and the value is returned.
+    fn insert_at(&mut self, index: usize, value: I::Item) -> Option<I::Item> {
+        let len = self.len;
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if self.is_full() {
+            if self.overflow == Overflow::Reject || index == 0 {
+                return Some(value);
+            }
+
+            let head = self.slide();
+            self.insert_at(index - 1, value);
+            self.debug_assert_invariants();
+            return head;
+        }
+
+        self.push_back_unchecked(value);
+
+        for i in (index..len).rev() {
+            let (i, j) = (self.physical(i), self.physical(i + 1));
+            self.tape.swap(i, j);
+        }
+
+        self.debug_assert_invariants();
+        None
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<I::Item> {
+        if index >= self.len {
+            return None;
+        }
+
+        // move the item to the tail, so it can be removed from there
+        for i in index..self.len - 1 {
+            let (i, j) = (self.physical(i), self.physical(i + 1));
+            self.tape.swap(i, j);
+        }
+
+        let item = self.pop_back();
+        self.debug_assert_invariants();
+
+        item
+    }
+
+    fn get_disjoint_mut(&mut self, i: usize, j: usize) -> Option<(&mut I::Item, &mut I::Item)> {
+        let (first, second) = self.as_mut_slices();
+        ribbon::get_disjoint_mut(first, second, i, j)
+    }
+
+    fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation is larger than the length of the `Band`"
+        );
+
+        if n == 0 {
+            return;
+        }
+
+        if self.is_full() {
+            self.head = (self.head + n) % LEN;
+            self.debug_assert_invariants();
+            return;
+        }
+
+        for _ in 0..n {
+            if let Some(item) = self.slide() {
+                self.push_back_unchecked(item);
+            }
+        }
+    }
+
+    fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation is larger than the length of the `Band`"
+        );
+
+        if n == 0 {
+            return;
+        }
+
+        if self.is_full() {
+            self.head = (self.head + LEN - n) % LEN;
+            self.debug_assert_invariants();
+            return;
+        }
+
+        for _ in 0..n {
+            if let Some(item) = self.pop_back() {
+                self.push_front_unchecked(item);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let (first, second): (*mut [I::Item], *mut [I::Item]) = (first, second);
+
+        // reset first, so that a panicking destructor leaks items rather than dropping them twice
+        self.head = 0;
+        self.len = 0;
+
+        // SAFETY: slices held the initialized items, which are now treated as uninitialized.
+        unsafe {
+            ptr::drop_in_place(first);
+            ptr::drop_in_place(second);
+        }
+
+        self.debug_assert_invariants();
+    }
+
+    fn capacity(&self) -> usize {
+        LEN
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == LEN
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<const LEN: usize, I> Index<usize> for Band<LEN, I>
+where
+    I: Iterator,
+{
+    type Output = I::Item;
+
+    /// Returns a reference to the item at the given index of the `Band`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+
+        match self.peek_at(index) {
+            Some(item) => item,
+            _ => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<const LEN: usize, I> IndexMut<usize> for Band<LEN, I>
+where
+    I: Iterator,
+{
+    /// Returns a mutable reference to the item at the given index of the `Band`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+
+        match self.peek_at_mut(index) {
+            Some(item) => item,
+            _ => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+/// Iterates over references to the items currently found on the `Band`, from head to tail. Items
+/// are not consumed, and the `Band` is not expanded.
+impl<'a, const LEN: usize, I> IntoIterator for &'a Band<LEN, I>
+where
+    I: Iterator,
+{
+    type Item = &'a I::Item;
+    type IntoIter = Chain<slice::Iter<'a, I::Item>, slice::Iter<'a, I::Item>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second.iter())
+    }
+}
+
+/// Yields the items found on the `Band` from head to tail, filling the `Band` whenever it is empty.
+/// Iterating over the `Band` therefore yields the items found on it, followed by the remaining items
+/// of the wrapped iterator. Use [`Band::into_buffered_iter`] to only yield the items found on the
+/// `Band`.
+impl<const LEN: usize, I> Iterator for Band<LEN, I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // once the iterator is exhausted, it is not polled again, so this is only a cheap check
+        if self.is_empty() {
+            self.expand_n(LEN);
+        }
+
+        self.pop_front()
+    }
+}
+
+impl<const LEN: usize, I> Default for Band<LEN, I>
+where
+    I: Iterator + Default,
+{
+    fn default() -> Self {
+        Band::new(I::default())
+    }
+}
+
+impl<const LEN: usize, I> From<I> for Band<LEN, I>
+where
+    I: Iterator,
+{
+    fn from(value: I) -> Self {
+        Band::new(value)
+    }
+}
+
+/// Prints the items currently found on the `Band` in logical order, from head to tail, rather than
+/// the raw slots of the inner array. The wrapped iterator is not printed, so it does not need to
+/// implement `Debug`.
+impl<const LEN: usize, I> fmt::Debug for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, second) = self.as_slices();
+
+        f.debug_struct("Band")
+            .field("items", &Items(first, second))
+            .field("capacity", &LEN)
+            .field("overflow", &self.overflow)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Pair of slices that is debug printed as a single list.
+struct Items<'a, T>(&'a [T], &'a [T]);
+
+impl<T> fmt::Debug for Items<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0).entries(self.1).finish()
+    }
+}
+
+impl<const LEN: usize, I> Clone for Band<LEN, I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut band = Self {
+            iter: self.iter.clone(),
+            tape: [const { MaybeUninit::uninit() }; LEN],
+            head: 0,
+            len: 0,
+            overflow: self.overflow,
+        };
+
+        for item in self.iter() {
+            band.tape[band.len].write(item.clone());
+            band.len += 1;
+        }
+
+        band
+    }
+}
+
+/// Compares the items currently found on the `Band`s, from head to tail. Where the items are stored
+/// in the inner array, as well as the wrapped iterators, are not taken into account.
+impl<const LEN: usize, I> PartialEq for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.content_eq(other)
+    }
+}
+
+impl<const LEN: usize, I> Eq for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: Eq,
+{
+}
+
+/// Compares the items currently found on the `Band`, from head to tail, with the items of the slice.
+impl<const LEN: usize, I, U> PartialEq<[U]> for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(item, other)| item == other)
+    }
+}
+
+impl<const LEN: usize, I, U> PartialEq<&[U]> for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self == *other
+    }
+}
+
+impl<const LEN: usize, I, U, const N: usize> PartialEq<[U; N]> for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self == &other[..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, I, U> PartialEq<Vec<U>> for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+/// Compares the items currently found on the `Band`s lexicographically, from head to tail. Where
+/// the items are stored in the inner array, as well as the wrapped iterators, are not taken into
+/// account.
+impl<const LEN: usize, I> PartialOrd for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<const LEN: usize, I> Ord for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the items currently found on the `Band`, from head to tail, consistently with
+/// [`PartialEq`].
+impl<const LEN: usize, I> Hash for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<const LEN: usize, I> Drop for Band<LEN, I>
+where
+    I: Iterator,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Serializes the items currently found on the `Band`, from head to tail. The wrapped iterator is
+/// not serialized.
+#[cfg(feature = "serde")]
+impl<const LEN: usize, I> serde::Serialize for Band<LEN, I>
+where
+    I: Iterator,
+    I::Item: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes the items found on the `Band`, from head to tail. Since the iterator is not
+/// serialized, the `Band` wraps the [`Default`] value of the iterator.
+///
+/// Round-trip preserves the items found on the `Band`, but not the items the iterator would produce.
+/// Deserializing more than `LEN` items fails.
+#[cfg(feature = "serde")]
+impl<'de, const LEN: usize, I> serde::Deserialize<'de> for Band<LEN, I>
+where
+    I: Iterator + Default,
+    I::Item: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct BandVisitor<const LEN: usize, I>(core::marker::PhantomData<I>);
+
+        impl<'de, const LEN: usize, I> serde::de::Visitor<'de> for BandVisitor<LEN, I>
+        where
+            I: Iterator + Default,
+            I::Item: serde::Deserialize<'de>,
+        {
+            type Value = Band<LEN, I>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "a sequence of at most {LEN} items")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut band = Band::new(I::default());
+
+                while let Some(item) = seq.next_element()? {
+                    if band.is_full() {
+                        return Err(serde::de::Error::invalid_length(LEN + 1, &self));
+                    }
+
+                    band.tape[band.len].write(item);
+                    band.len += 1;
+                }
+
+                Ok(band)
+            }
+        }
+
+        deserializer.deserialize_seq(BandVisitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::{Band, Band16, Band32, Band8, Overflow};
+    use crate::{ribbon::Ribbon, Enroll, EnrollInto};
+
+    #[test]
+    fn expands() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
 
         assert_eq!(band.peek_front(), None);
         assert_eq!(band.peek_back(), None);
@@ -328,6 +1431,1684 @@ mod tests {
         assert_eq!(band.peek_back(), Some(&42));
     }
 
+    #[test]
+    fn expand_reports_exhaustion() {
+        let mut band: Band<3, _> = Band::new(0u32..3u32);
+
+        assert!(band.expand());
+        assert!(band.expand());
+        assert!(band.expand());
+        assert_eq!(band.len(), 3);
+
+        // band is full and iterator drained, nothing is pulled and nothing is dropped
+        assert!(!band.expand());
+        assert_eq!(band.len(), 3);
+        assert_eq!(band.peek_front(), Some(&0));
+        assert!(!band.expand());
+        assert_eq!(band.len(), 3);
+    }
+
+    #[test]
+    fn expands_after_pops() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+
+        assert_eq!(band.pop_front(), Some(0));
+        assert_eq!(band.pop_front(), Some(1));
+        assert_eq!(band.len(), 3);
+
+        assert!(band.expand());
+        assert_eq!(band.len(), 4);
+        assert_eq!(band.peek_front(), Some(&2));
+        assert_eq!(band.peek_back(), Some(&5));
+
+        assert!(band.expand());
+        assert_eq!(band.len(), 5);
+        assert_eq!(band.peek_at(3), Some(&5));
+        assert_eq!(band.peek_back(), Some(&6));
+
+        // band is full, expanding drops the head
+        assert!(band.expand());
+        assert_eq!(band.len(), 5);
+        assert_eq!(band.peek_front(), Some(&3));
+        assert_eq!(band.peek_back(), Some(&7));
+    }
+
+    #[test]
+    fn peek_back_on_empty() {
+        let band: Band<5, _> = Band::new(0u32..10u32);
+
+        assert!(band.is_empty());
+        assert_eq!(band.peek_back(), None);
+    }
+
+    #[test]
+    fn iterates_in_logical_order() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+        band.pop_front();
+        band.pop_front();
+        band.expand_n(2);
+
+        let items: Vec<_> = band.iter().collect();
+        let peeked: Vec<_> = (0..band.len()).filter_map(|i| band.peek_at(i)).collect();
+
+        assert_eq!(items, peeked);
+        assert_eq!(items, vec![&2, &3, &4, &5, &6]);
+    }
+
+    #[test]
+    fn mutates_in_logical_order() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+        band.pop_front();
+        band.pop_front();
+        band.expand_n(2);
+
+        for (i, item) in band.iter_mut().enumerate() {
+            *item = i as u32 * 10;
+        }
+
+        assert_eq!(band.peek_at(0), Some(&0));
+        assert_eq!(band.peek_at(1), Some(&10));
+        assert_eq!(band.peek_at(2), Some(&20));
+        assert_eq!(band.peek_at(3), Some(&30));
+        assert_eq!(band.peek_at(4), Some(&40));
+    }
+
+    #[test]
+    fn into_inner_continues() {
+        let mut band = (0..10).band::<3>();
+        band.expand_n(3);
+        band.progress();
+
+        let rest: Vec<_> = band.into_inner().collect();
+        assert_eq!(rest, vec![4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn into_buffered_iter() {
+        let mut band = (0..10).band::<3>();
+        band.expand_n(5);
+
+        let items: Vec<_> = band.clone().into_buffered_iter().collect();
+        assert_eq!(items, vec![2, 3, 4]);
+
+        // iterating over the band itself continues with the wrapped iterator
+        let items: Vec<_> = band.collect();
+        assert_eq!(items, (2..10).collect::<Vec<_>>());
+
+        let mut drained = (0..10).band::<3>();
+        drained.expand_n(3);
+        drained.drain_front(3).for_each(drop);
+        assert_eq!(drained.into_buffered_iter().next(), None);
+    }
+
+    #[test]
+    fn clears() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+        band.pop_front();
+        band.pop_front();
+
+        band.clear();
+        assert_eq!(band.len(), 0);
+        assert!(band.is_empty());
+        assert_eq!(band.peek_front(), None);
+        assert_eq!(band.peek_back(), None);
+
+        band.expand_n(2);
+        assert_eq!(band.len(), 2);
+        assert_eq!(band.peek_front(), Some(&5));
+        assert_eq!(band.peek_back(), Some(&6));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut band: Band<3, _> = Band::new(0u32..10u32);
+        band.expand_n(3);
+        band.pop_front();
+        band.expand();
+
+        let json = serde_json::to_string(&band).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let restored: Band<3, std::ops::Range<u32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.peek_at(0), band.peek_at(0));
+        assert_eq!(restored.peek_at(1), band.peek_at(1));
+        assert_eq!(restored.peek_at(2), band.peek_at(2));
+
+        let too_long = serde_json::from_str::<Band<2, std::ops::Range<u32>>>(&json);
+        assert!(too_long.is_err());
+    }
+
+    #[test]
+    fn peeks_n() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(2);
+
+        // under-filled, only the available items are returned
+        assert_eq!(band.peek_front_n(3).collect::<Vec<_>>(), vec![&0, &1]);
+        assert_eq!(band.peek_back_n(3).collect::<Vec<_>>(), vec![&0, &1]);
+
+        // wrap around the end of the inner array
+        band.expand_n(5);
+        assert_eq!(band.peek_front_n(3).collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(band.peek_back_n(3).collect::<Vec<_>>(), vec![&4, &5, &6]);
+        assert_eq!(band.len(), 5);
+    }
+
+    #[test]
+    fn peeks_range() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(2);
+
+        // under-filled, the range is clamped to the available items
+        assert_eq!(band.peek_range(1..4).collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(band.peek_range(5..).count(), 0);
+
+        // wrap around the end of the inner array
+        band.expand_n(5);
+        assert_eq!(band.peek_range(1..3).collect::<Vec<_>>(), vec![&3, &4]);
+        assert_eq!(band.peek_range(2..=4).collect::<Vec<_>>(), vec![&4, &5, &6]);
+        assert_eq!(band.peek_range(..2).collect::<Vec<_>>(), vec![&2, &3]);
+        assert_eq!(band.peek_range(..).count(), 5);
+        assert_eq!(band.peek_range(3..10).collect::<Vec<_>>(), vec![&5, &6]);
+        assert_eq!(band.len(), 5);
+    }
+
+    #[test]
+    fn expands_until() {
+        let mut band: Band<3, _> = Band::new([1, 2, 0, 3, 4, 5, 0, 6].into_iter());
+
+        assert!(band.expand_until(|item| *item == 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &0]);
+
+        // band is full, items are dropped from the head until delimiter is appended
+        assert!(band.expand_until(|item| *item == 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &5, &0]);
+
+        assert!(band.expand_until(|item| *item == 0));
+        assert_eq!(band.peek_back(), Some(&6));
+        assert!(!band.expand_until(|item| *item == 0));
+    }
+
+    #[test]
+    fn progresses_n() {
+        let mut band: Band<3, _> = Band::new(0u32..10u32);
+        band.expand_n(3);
+
+        assert_eq!(band.progress_n(4).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+
+        // iterator is exhausted after 3 more items
+        assert_eq!(band.progress_n(5).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&7, &8, &9]);
+    }
+
+    #[test]
+    fn progresses_while() {
+        let mut band: Band<3, _> = Band::new([0, 0, 0, 0, 1, 2, 0, 3].into_iter());
+        band.expand_n(3);
+
+        assert!(band.progress_while(|item| *item == 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &0]);
+
+        assert!(!band.progress_while(|item| *item == 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &0]);
+
+        // stops when the iterator is exhausted, even though the head satisfies the condition
+        assert!(band.progress_while(|item| *item != 3));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &0, &3]);
+    }
+
+    #[test]
+    fn indexes() {
+        let mut band: Band<3, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+
+        assert_eq!(band[0], 2);
+        assert_eq!(band[2], 4);
+
+        band[1] = 42;
+        assert_eq!(band[1], 42);
+        assert_eq!(band.peek_at(1), Some(&42));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_out_of_bounds() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(3);
+
+        let _ = band[3];
+    }
+
+    #[test]
+    fn capacity() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        assert_eq!(band.capacity(), 5);
+
+        band.expand_n(10);
+        assert_eq!(band.capacity(), 5);
+    }
+
+    #[test]
+    fn drains_front() {
+        let mut band: Band<4, _> = Band::new(0u32..10u32);
+        band.expand_n(6);
+
+        assert_eq!(band.drain_front(3).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(band.len(), 1);
+        assert_eq!(band.peek_front(), Some(&5));
+        assert_eq!(band.peek_back(), Some(&5));
+
+        band.expand_n(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &6, &7]);
+
+        assert_eq!(band.drain_front(10).collect::<Vec<_>>(), vec![5, 6, 7]);
+        assert!(band.is_empty());
+        assert_eq!(band.peek_front(), None);
+    }
+
+    #[test]
+    fn searches_logical_order() {
+        let mut band: Band<4, _> = Band::new(0u32..10u32);
+        band.expand_n(6);
+
+        assert!(band.contains(&5));
+        assert!(!band.contains(&1));
+
+        // logical index, physically stored at index 0
+        assert_eq!(band.position(|item| *item == 4), Some(2));
+        assert_eq!(band.position(|item| *item == 5), Some(3));
+        assert_eq!(band.position(|item| *item > 10), None);
+    }
+
+    #[test]
+    fn expands_while_indexed() {
+        let mut band: Band<4, _> = Band::new([1, 2, 3, 4, 5, 6, 0].into_iter());
+
+        // values keep ascending, but the index stops the expansion
+        assert!(band.expand_while_indexed(|i, item| i < 3 && *item > 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        assert!(band.expand_while_indexed(|i, item| i < 3 && *item > 0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4, &5, &6]);
+
+        assert!(!band.expand_while_indexed(|_, item| *item > 0));
+        assert_eq!(band.len(), 4);
+    }
+
+    #[test]
+    fn as_slices() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(3);
+        band.pop_front();
+
+        // not wrapped
+        assert_eq!(band.as_slices(), (&[1, 2][..], &[][..]));
+
+        // wrapped
+        band.expand_n(3);
+        assert_eq!(band.as_slices(), (&[1, 2, 3, 4][..], &[5][..]));
+
+        let (first, second) = band.as_mut_slices();
+        first[0] = 42;
+        second[0] = 43;
+        assert_eq!(band.peek_front(), Some(&42));
+        assert_eq!(band.peek_back(), Some(&43));
+    }
+
+    /// Item that counts how many times it was dropped.
+    struct Tracked(Rc<Cell<usize>>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// Tracked items carry no value, so they are all equal.
+    impl PartialEq for Tracked {
+        fn eq(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    /// Iterator producing `n` tracked items, that is itself tracked when dropped.
+    fn tracked_source(drops: &Rc<Cell<usize>>, n: usize) -> impl Iterator<Item = Tracked> {
+        let source_guard = Rc::new(Tracked(Rc::clone(drops)));
+        let drops = Rc::clone(drops);
+
+        (0..n).map(move |_| {
+            let _ = &source_guard;
+            Tracked(Rc::clone(&drops))
+        })
+    }
+
+    #[test]
+    fn drops_live_items() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<4, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(3);
+        assert_eq!(drops.get(), 0);
+
+        // wrap around, dropping 2 items from the head
+        band.expand_n(3);
+        assert_eq!(drops.get(), 2);
+
+        drop(band.pop_front());
+        assert_eq!(drops.get(), 3);
+
+        drop(band.pop_back());
+        assert_eq!(drops.get(), 4);
+
+        drop(band.progress());
+        assert_eq!(drops.get(), 5);
+
+        // remaining 2 items and the source are dropped with the band
+        drop(band);
+        assert_eq!(drops.get(), 8);
+    }
+
+    #[test]
+    fn drops_on_clear() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(5);
+        assert_eq!(drops.get(), 2);
+
+        band.clear();
+        assert_eq!(drops.get(), 5);
+
+        band.expand();
+        drop(band);
+        assert_eq!(drops.get(), 7);
+    }
+
+    #[test]
+    fn drops_empty_and_full() {
+        let drops = Rc::new(Cell::new(0));
+
+        // only the source is dropped
+        let band: Band<3, _> = Band::new(tracked_source(&drops, 10));
+        drop(band);
+        assert_eq!(drops.get(), 1);
+
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(3);
+        drop(band);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn drops_on_into_inner() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(4);
+        assert_eq!(drops.get(), 1);
+
+        // buffered items are dropped, source is returned
+        let source = band.into_inner();
+        assert_eq!(drops.get(), 4);
+
+        drop(source);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn drops_clones_separately() {
+        let drops = Rc::new(Cell::new(0));
+        let items: Vec<_> = (0..4)
+            .map(|_| Rc::new(Tracked(Rc::clone(&drops))))
+            .collect();
+
+        let mut band: Band<3, _> = Band::new(items.into_iter());
+        band.expand_n(4);
+        assert_eq!(drops.get(), 1);
+
+        // items are shared through `Rc`, the clone keeps them alive
+        let cloned = band.clone();
+        drop(band);
+        assert_eq!(drops.get(), 1);
+
+        drop(cloned);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn content_eq_ignores_layout() {
+        let mut wrapped: Band<4, _> = Band::new(0u32..10u32);
+        wrapped.expand_n(7);
+
+        let mut filled: Band<4, _> = Band::new(3u32..10u32);
+        filled.expand_n(4);
+
+        assert!(wrapped.content_eq(&filled));
+        assert!(filled.content_eq(&wrapped));
+
+        filled.pop_back();
+        assert!(!wrapped.content_eq(&filled));
+    }
+
+    #[test]
+    fn compares_logical_order() {
+        let mut rotated: Band<3, _> = Band::new(0u32..10u32);
+        rotated.expand_n(3);
+        rotated.progress();
+        rotated.progress();
+
+        let mut filled: Band<3, _> = Band::new(2u32..10u32);
+        filled.expand_n(3);
+
+        assert_eq!(rotated, filled);
+        assert_eq!(Ord::cmp(&rotated, &filled), std::cmp::Ordering::Equal);
+
+        filled.progress();
+        assert_ne!(rotated, filled);
+        assert!(rotated < filled);
+
+        filled.pop_back();
+        assert!(rotated < filled);
+    }
+
+    #[test]
+    fn hashes_logical_order() {
+        use std::hash::BuildHasher;
+
+        let hasher = std::collections::hash_map::RandomState::new();
+
+        let mut rotated: Band<3, _> = Band::new(0u32..10u32);
+        rotated.expand_n(5);
+
+        let mut filled: Band<3, _> = Band::new(2u32..10u32);
+        filled.expand_n(3);
+
+        assert_eq!(rotated, filled);
+        assert_eq!(hasher.hash_one(&rotated), hasher.hash_one(&filled));
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(rotated);
+        assert!(set.contains(&filled));
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Lexer {
+            band: Band<3, std::ops::Range<u32>>,
+        }
+
+        let mut lexer = Lexer::default();
+        assert_eq!(lexer.band.len(), 0);
+        assert!(lexer.band.is_empty());
+        assert!(!lexer.band.expand());
+    }
+
+    #[test]
+    fn rotates() {
+        // full band, rotation moves the head
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(7);
+
+        band.rotate_left(4);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&6, &2, &3, &4, &5]);
+        assert_eq!(band.peek_front(), Some(&6));
+        assert_eq!(band.peek_back(), Some(&5));
+
+        band.rotate_right(4);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5, &6]);
+
+        // partially filled band, crossing the end of the inner array
+        band.pop_front();
+        band.pop_front();
+        assert_eq!(band.as_slices(), (&[4][..], &[5, 6][..]));
+
+        band.rotate_left(1);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &6, &4]);
+        assert_eq!(band.peek_front(), Some(&5));
+        assert_eq!(band.peek_back(), Some(&4));
+
+        band.rotate_right(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&6, &4, &5]);
+        assert_eq!(band.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_larger_than_len() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(2);
+
+        band.rotate_left(3);
+    }
+
+    #[test]
+    fn retains() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(8);
+
+        band.retain(|item| item % 2 == 0);
+        assert_eq!(band.len(), 2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6]);
+        assert_eq!(band.peek_back(), Some(&6));
+
+        band.expand_n(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6, &8, &9]);
+
+        band.retain(|_| false);
+        assert!(band.is_empty());
+    }
+
+    #[test]
+    fn retain_drops_removed() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<4, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(4);
+
+        let mut index = 0;
+        band.retain(|_| {
+            index += 1;
+            index % 2 == 0
+        });
+        assert_eq!(drops.get(), 2);
+        assert_eq!(band.len(), 2);
+
+        drop(band);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn swaps() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(7);
+
+        // head and tail are physically stored at indices 2 and 1
+        band.swap(0, 4);
+        assert_eq!(band.peek_at(0), Some(&6));
+        assert_eq!(band.peek_at(4), Some(&2));
+        assert_eq!(band.peek_at(2), Some(&4));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn swap_out_of_bounds() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(3);
+
+        band.swap(0, 3);
+    }
+
+    #[test]
+    fn truncates() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(7);
+
+        band.truncate(7);
+        assert_eq!(band.len(), 5);
+
+        band.truncate(2);
+        assert_eq!(band.len(), 2);
+        assert_eq!(band.peek_front(), Some(&2));
+        assert_eq!(band.peek_back(), Some(&3));
+
+        band.expand();
+        assert_eq!(band.peek_back(), Some(&7));
+
+        band.truncate(0);
+        assert!(band.is_empty());
+        assert_eq!(band.peek_back(), None);
+    }
+
+    #[test]
+    fn pops_front_if() {
+        let mut band: Band<3, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+
+        assert_eq!(band.pop_front_if(|item| *item == 3), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        assert_eq!(band.pop_front_if(|item| *item == 2), Some(2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4]);
+    }
+
+    #[test]
+    fn fills() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand();
+
+        assert!(band.fill());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &3, &4]);
+
+        // already full, nothing is dropped
+        assert!(band.fill());
+        assert_eq!(band.peek_front(), Some(&0));
+
+        // iterator is exhausted before the band is full
+        let mut band: Band<5, _> = Band::new(0u32..3u32);
+        assert!(!band.fill());
+        assert_eq!(band.len(), 3);
+    }
+
+    #[test]
+    fn progresses_or_expands() {
+        let mut band: Band<3, _> = Band::new(0u32..5u32);
+
+        assert_eq!(band.progress_or_expand(), None);
+        assert_eq!(band.progress_or_expand(), None);
+        assert_eq!(band.progress_or_expand(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+
+        assert_eq!(band.progress_or_expand(), Some(0));
+        assert_eq!(band.progress_or_expand(), Some(1));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        // iterator is exhausted
+        assert_eq!(band.progress_or_expand(), None);
+        assert_eq!(band.len(), 3);
+    }
+
+    #[test]
+    fn peeks_nothing_after_popping_everything() {
+        let mut band: Band<5, _> = Band::new(0u32..10u32);
+        band.expand_n(5);
+        band.progress_n(2).for_each(drop);
+
+        while band.pop_back().is_some() {}
+
+        assert!(band.is_empty());
+        assert_eq!(band.peek_back(), None);
+        assert_eq!(band.peek_front(), None);
+        assert_eq!(band.peek_back_mut(), None);
+        assert_eq!(band.peek_front_mut(), None);
+        assert_eq!(band.iter().count(), 0);
+    }
+
+    #[test]
+    fn interleaved_operations_keep_items_consistent() {
+        let mut band: Band<4, _> = Band::new(0..100);
+        let mut model = std::collections::VecDeque::new();
+        let mut next = 0;
+
+        for step in 0..200 {
+            match step % 7 {
+                0 | 3 | 5 => {
+                    if band.expand() {
+                        if model.len() == 4 {
+                            model.pop_front();
+                        }
+                        model.push_back(next);
+                        next += 1;
+                    }
+                }
+                1 | 6 => {
+                    let head = band.progress();
+                    if next < 100 {
+                        assert_eq!(head, model.pop_front());
+                        model.push_back(next);
+                        next += 1;
+                    } else {
+                        assert_eq!(head, None);
+                    }
+                }
+                2 => assert_eq!(band.pop_front(), model.pop_front()),
+                _ => assert_eq!(band.pop_back(), model.pop_back()),
+            }
+
+            assert_eq!(band.len(), model.len());
+            assert!(band.iter().eq(model.iter()));
+        }
+    }
+
+    #[test]
+    fn interleaved_operations_drop_each_item_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 50));
+        let mut produced = 0;
+
+        for step in 0..60 {
+            let len = band.len();
+
+            match step % 5 {
+                0 | 2 => produced += usize::from(band.expand()),
+                1 => {
+                    let expanded = band.progress().is_some() || band.len() > len;
+                    produced += usize::from(expanded);
+                }
+                3 => drop(band.pop_front()),
+                _ => drop(band.pop_back()),
+            }
+
+            assert_eq!(drops.get(), produced - band.len());
+        }
+
+        drop(band);
+
+        // every produced item and the source itself
+        assert_eq!(drops.get(), produced + 1);
+    }
+
+    #[test]
+    fn iterates_by_reference() {
+        let mut band: Band<4, _> = Band::new(0..10);
+        band.expand_n(6);
+
+        let mut items = Vec::new();
+        for item in &band {
+            items.push(*item);
+        }
+
+        // wrapped around the end of the inner array
+        assert_eq!(items, vec![2, 3, 4, 5]);
+        assert_eq!(band.len(), 4);
+        assert_eq!(band.peek_front(), Some(&2));
+        assert_eq!(band.peek_back(), Some(&5));
+    }
+
+    #[test]
+    fn collects_remaining() {
+        let mut band: Band<4, _> = Band::new(0..10);
+        band.expand_n(6);
+
+        // wrapped around the end of the inner array
+        let items: Vec<_> = band.collect_remaining();
+        assert_eq!(items, (2..10).collect::<Vec<_>>());
+
+        let band: Band<4, _> = Band::new(0..5);
+        let items: Vec<_> = band.collect_remaining();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity() {
+        let _band: Band<0, _> = Band::new(0..10);
+    }
+
+    #[test]
+    fn large_capacity() {
+        let mut band: Band<4096, _> = Band::new(0u64..);
+        band.expand_n(5000);
+
+        assert_eq!(band.len(), 4096);
+        assert_eq!(band.peek_front(), Some(&904));
+        assert_eq!(band.peek_back(), Some(&4999));
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity_when_enrolled() {
+        let _band = (0..10).band::<0>();
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity_when_enrolled_into() {
+        let _band = vec![1, 2, 3].into_band::<0>();
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity_by_default() {
+        let _band: Band<0, core::ops::Range<u32>> = Band::default();
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity_from_iterator() {
+        let _band: Band<0, _> = Band::from(0..10);
+    }
+
+    #[test]
+    fn advances() {
+        let mut band: Band<3, _> = Band::new(0u32..);
+
+        // fills the band on the first call
+        assert_eq!(band.advance(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+
+        // then evicts the head on each call
+        for expected in 0..10 {
+            assert_eq!(band.advance(), Some(expected));
+            assert_eq!(band.len(), 3);
+            assert_eq!(band.peek_back(), Some(&(expected + 3)));
+        }
+    }
+
+    #[test]
+    fn advances_short_source() {
+        let mut band: Band<3, _> = Band::new(0u32..2u32);
+
+        // the band can not be filled, so nothing is evicted
+        assert_eq!(band.advance(), None);
+        assert_eq!(band.advance(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+
+        let mut band: Band<3, _> = Band::new(0u32..4u32);
+        assert_eq!(band.advance(), None);
+        assert_eq!(band.advance(), Some(0));
+
+        // iterator is exhausted, the band is left untouched
+        assert_eq!(band.advance(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn overflow_drops_oldest() {
+        let mut band: Band<3, _> = Band::new(0..10);
+        assert_eq!(band.overflow(), Overflow::DropOldest);
+
+        assert!(band.expand_n(4));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn overflow_rejects() {
+        let mut band: Band<3, _> = Band::new_bounded(0..10);
+        assert_eq!(band.overflow(), Overflow::Reject);
+
+        assert!(band.expand_n(3));
+        assert!(!band.expand());
+        assert!(!band.expand_n(2));
+        assert!(!band.expand_while(|_| true));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+
+        // the iterator was not polled while full
+        band.pop_front();
+        assert!(band.expand());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        // partially expands until full
+        band.pop_front();
+        band.pop_front();
+        assert!(band.expand_while(|_| true));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4, &5]);
+
+        band.set_overflow(Overflow::DropOldest);
+        assert!(band.expand());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+        assert_eq!(band.clone().overflow(), Overflow::DropOldest);
+    }
+
+    #[test]
+    fn expand_returning() {
+        let drops = Rc::new(Cell::new(0));
+        let mut band: Band<2, _> = Band::new(tracked_source(&drops, 4));
+
+        assert!(band.expand_returning().is_none());
+        assert!(band.expand_returning().is_none());
+        assert_eq!(band.len(), 2);
+
+        // evicted item is handed over instead of being dropped
+        let evicted = band.expand_returning();
+        assert!(evicted.is_some());
+        assert_eq!(drops.get(), 0);
+        drop(evicted);
+        assert_eq!(drops.get(), 1);
+
+        assert!(band.expand_returning().is_some());
+        assert_eq!(drops.get(), 2);
+
+        // iterator is exhausted, nothing is evicted
+        assert!(band.expand_returning().is_none());
+        assert_eq!(band.len(), 2);
+    }
+
+    #[test]
+    fn expand_returning_rejected() {
+        let mut band: Band<2, _> = Band::new_bounded(0..10);
+        band.expand_n(2);
+
+        assert_eq!(band.expand_returning(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+    }
+
+    #[test]
+    fn expands_n_counted() {
+        let mut band: Band<3, _> = Band::new(0..5);
+
+        // items dropped from the head are counted as appended
+        assert_eq!(band.expand_n_counted(4), 4);
+        assert_eq!(band.expand_n_counted(4), 1);
+        assert_eq!(band.expand_n_counted(4), 0);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        // a bounded band stops counting once full
+        let mut band: Band<3, _> = Band::new_bounded(0..5);
+        assert_eq!(band.expand_n_counted(4), 3);
+    }
+
+    #[test]
+    fn peeks_back_at() {
+        let mut band: Band<4, _> = Band::new(0..10);
+        assert_eq!(band.peek_back_at(0), None);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.peek_back_at(0), band.peek_back());
+        assert_eq!(band.peek_back_at(1), Some(&4));
+        assert_eq!(band.peek_back_at(3), Some(&2));
+        assert_eq!(band.peek_back_at(4), None);
+        assert_eq!(band.peek_back_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn ends() {
+        let mut band: Band<3, _> = Band::new(0..10);
+        assert_eq!(band.ends(), (None, None));
+        assert_eq!(band.ends_mut(), (None, None));
+
+        // a single item is both the head and the tail, but is not aliased mutably
+        band.expand();
+        assert_eq!(band.ends(), (Some(&0), Some(&0)));
+        assert_eq!(band.ends_mut(), (Some(&mut 0), None));
+
+        // wrap around the end of the inner array
+        band.expand_n(4);
+        assert_eq!(band.ends(), (Some(&2), Some(&4)));
+
+        if let (Some(front), Some(back)) = band.ends_mut() {
+            *front = 20;
+            *back = 40;
+        }
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&20, &3, &40]);
+    }
+
+    #[test]
+    fn source_exhausted() {
+        let mut band: Band<2, _> = Band::new(0..3);
+        assert!(!band.source_exhausted());
+
+        band.expand_n(2);
+        assert!(!band.source_exhausted());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+
+        // peeked item is not lost
+        band.expand();
+        assert!(band.source_exhausted());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn stays_drained() {
+        /// Iterator that resumes producing items after returning `None`.
+        struct Flaky(u32);
+
+        impl Iterator for Flaky {
+            type Item = u32;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0 += 1;
+                (self.0 != 3).then_some(self.0)
+            }
+        }
+
+        let mut band: Band<4, _> = Band::new(Flaky(0));
+        assert!(band.expand_n(2));
+        assert!(!band.expand());
+        assert!(!band.expand());
+        assert!(band.progress().is_none());
+        assert!(band.source_exhausted());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn takes() {
+        let drops = Rc::new(Cell::new(0));
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(2);
+
+        // budget is respected across expansions
+        let mut band = band.take(3);
+        assert!(band.expand());
+        assert!(band.expand_n(5));
+        assert!(!band.expand());
+        assert_eq!(band.len(), 3);
+        assert_eq!(drops.get(), 2);
+
+        // items are dropped exactly once
+        drop(band);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn expand_takes() {
+        let polls = Cell::new(0);
+        let source = (0..10).inspect(|_| polls.set(polls.get() + 1));
+
+        let mut band: Band<3, _> = Band::new(source);
+        band.expand();
+
+        // budget is respected across expansions and progressions
+        band.expand_take(3);
+        assert!(band.expand_n(2));
+        assert_eq!(band.progress(), Some(0));
+        assert!(!band.expand());
+        assert_eq!(band.progress(), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(polls.get(), 4);
+
+        // the source is not polled once the budget is spent
+        assert!(band.source_exhausted());
+        assert_eq!(polls.get(), 4);
+    }
+
+    #[test]
+    fn dedups_buffered() {
+        let mut band: Band<6, _> = Band::new([0, 0, 1, 1, 2, 2, 2, 3, 3].into_iter());
+
+        // wrap around the end of the inner array
+        band.expand_n(8);
+        assert_eq!(
+            band.iter().collect::<Vec<_>>(),
+            vec![&1, &1, &2, &2, &2, &3]
+        );
+
+        band.dedup_buffered();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(band.len(), 3);
+
+        // items expanded later are not deduplicated
+        band.expand();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &3]);
+    }
+
+    #[test]
+    fn dedup_buffered_drops_duplicates() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<3, _> = Band::new(tracked_source(&drops, 3));
+        band.expand_n(3);
+
+        band.dedup_buffered();
+        assert_eq!(band.len(), 1);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn folds_buffered() {
+        let mut band: Band<4, _> = Band::new(1..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+
+        assert_eq!(
+            band.fold_buffered(Vec::new(), |mut acc, item| {
+                acc.push(*item);
+                acc
+            }),
+            vec![3, 4, 5, 6]
+        );
+        assert_eq!(band.sum_buffered::<i32>(), 18);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4, &5, &6]);
+    }
+
+    #[test]
+    fn views() {
+        let mut band: Band<5, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(8);
+        assert_eq!(band.as_slices(), (&[3, 4][..], &[5, 6, 7][..]));
+
+        let view = band.view(1..4);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.peek_front(), Some(&4));
+        assert_eq!(view.peek_back(), Some(&6));
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+
+        // the band is unchanged
+        assert_eq!(band.len(), 5);
+        assert_eq!(band.peek_front(), Some(&3));
+    }
+
+    #[test]
+    fn expands_while_peek() {
+        let mut band: Band<2, _> = Band::new(0..6);
+
+        assert_eq!(band.expand_while_peek(|item| *item < 3), (true, Some(&3)));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2]);
+
+        // the peeked item was not appended
+        assert_eq!(band.expand_while_peek(|item| *item < 3), (false, Some(&3)));
+
+        // a bounded band stops before the condition fails
+        let mut band: Band<2, _> = Band::new_bounded(0..6);
+        assert_eq!(band.expand_while_peek(|item| *item < 3), (true, Some(&2)));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+    }
+
+    #[test]
+    fn replaces_at() {
+        let mut band: Band<4, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+
+        assert_eq!(band.replace_at(0, 12), Some(2));
+        assert_eq!(band.replace_at(2, 14), Some(4));
+        assert_eq!(band.replace_at(3, 15), Some(5));
+        assert_eq!(band.replace_at(4, 16), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&12, &3, &14, &15]);
+        assert_eq!(band.len(), 4);
+    }
+
+    #[test]
+    fn inserts_at() {
+        let mut band: Band<6, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(7);
+        band.pop_front();
+        band.pop_front();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4, &5, &6]);
+
+        assert_eq!(band.insert_at(0, 10), None);
+        assert_eq!(band.insert_at(2, 11), None);
+        assert_eq!(
+            band.iter().collect::<Vec<_>>(),
+            vec![&10, &3, &11, &4, &5, &6]
+        );
+
+        // full, the head is dropped to make room
+        assert_eq!(band.insert_at(6, 12), Some(10));
+        assert_eq!(band.insert_at(2, 13), Some(3));
+        assert_eq!(
+            band.iter().collect::<Vec<_>>(),
+            vec![&11, &13, &4, &5, &6, &12]
+        );
+
+        // inserting at the head of a full band drops the inserted value
+        assert_eq!(band.insert_at(0, 14), Some(14));
+        assert_eq!(band.len(), 6);
+    }
+
+    #[test]
+    fn insert_at_rejected() {
+        let mut band: Band<3, _> = Band::new_bounded(0..10);
+        band.expand_n(2);
+
+        assert_eq!(band.insert_at(1, 10), None);
+        assert_eq!(band.insert_at(1, 11), Some(11));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &10, &1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_at_out_of_bounds() {
+        let mut band: Band<3, _> = Band::new(0..10);
+        band.expand();
+        band.insert_at(2, 42);
+    }
+
+    #[test]
+    fn removes_at() {
+        let mut band: Band<5, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(8);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4, &5, &6, &7]);
+
+        assert_eq!(band.remove_at(0), Some(3));
+        assert_eq!(band.remove_at(1), Some(5));
+        assert_eq!(band.remove_at(2), Some(7));
+        assert_eq!(band.remove_at(2), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6]);
+        assert_eq!(band.len(), 2);
+
+        band.expand_n(3);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6, &8, &9]);
+    }
+
+    #[test]
+    fn remove_at_drops_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        // wrap around, dropping 1 item from the head
+        let mut band: Band<4, _> = Band::new(tracked_source(&drops, 5));
+        band.expand_n(5);
+
+        drop(band.remove_at(1));
+        assert_eq!(drops.get(), 2);
+
+        // remaining 3 items and the source are dropped with the band
+        drop(band);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn drains_range() {
+        let mut band: Band<5, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(7);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5, &6]);
+
+        assert_eq!(band.drain(1..3).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &5, &6]);
+
+        // the whole range is removed even if not consumed
+        let mut drain = band.drain(1..=2);
+        assert_eq!(drain.next(), Some(5));
+        drop(drain);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2]);
+
+        assert_eq!(band.drain(..).collect::<Vec<_>>(), vec![2]);
+        assert!(band.is_empty());
+
+        band.expand_n(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&7, &8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn drain_out_of_bounds() {
+        let mut band: Band<5, _> = Band::new(0..10);
+        band.expand_n(3);
+        drop(band.drain(2..4));
+    }
+
+    #[test]
+    fn expand_while_keeps_rejected_item() {
+        let mut band: Band<4, _> = Band::new(0..10);
+
+        assert!(band.expand_while(|item| *item < 2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+        assert_eq!(band.peek_source(), Some(&2));
+
+        // the rejected item is delivered by the next expansion
+        assert!(band.expand());
+        assert_eq!(band.peek_back(), Some(&2));
+
+        // rejected again, without being consumed
+        assert!(!band.expand_while(|item| *item > 5));
+        assert!(!band.expand_while(|item| *item > 5));
+        assert_eq!(band.progress(), Some(0));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(band.peek_source(), Some(&4));
+    }
+
+    #[test]
+    fn min_and_max_buffered() {
+        let mut band: Band<4, _> = Band::new([7, 9, 3, 9, 3, 5, 0].into_iter());
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &9, &3, &5]);
+
+        // first of equal items is returned
+        assert!(core::ptr::eq(band.min_buffered().unwrap(), &band[0]));
+        assert!(core::ptr::eq(band.max_buffered().unwrap(), &band[1]));
+        assert_eq!(band.len(), 4);
+
+        band.expand();
+        assert_eq!(band.min_buffered(), Some(&0));
+        assert_eq!(band.max_buffered(), Some(&9));
+    }
+
+    #[test]
+    fn counts_buffered() {
+        let mut band: Band<6, _> = Band::new(0..10);
+        band.expand_n(6);
+        assert_eq!(band.count_buffered(|item| item % 2 == 0), 3);
+
+        // wrap around the end of the inner array
+        band.expand_n(3);
+        assert_eq!(
+            band.iter().collect::<Vec<_>>(),
+            vec![&3, &4, &5, &6, &7, &8]
+        );
+        assert_eq!(band.count_buffered(|item| item % 2 == 0), 3);
+        assert_eq!(band.count_buffered(|item| *item > 6), 2);
+        assert_eq!(band.len(), 6);
+    }
+
+    #[test]
+    fn resizes() {
+        let mut band: Band<3, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(4);
+
+        let mut band: Band<5, _> = band.resize();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(band.capacity(), 5);
+
+        assert!(band.expand_n(3));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5, &6]);
+
+        // the tail is kept when dropping the oldest items
+        let band: Band<2, _> = band.resize();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &6]);
+        assert_eq!(band.overflow(), Overflow::DropOldest);
+        assert_eq!(band.into_inner().next(), Some(7));
+    }
+
+    #[test]
+    fn resize_rejected() {
+        let mut band: Band<5, _> = Band::new_bounded(0..10);
+        band.expand_n(5);
+
+        // the head is kept when rejecting new items
+        let mut band: Band<2, _> = band.resize();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+        assert_eq!(band.overflow(), Overflow::Reject);
+        assert!(!band.expand());
+
+        band.pop_front();
+        assert!(band.expand());
+        assert_eq!(band.peek_back(), Some(&5));
+    }
+
+    #[test]
+    fn resize_drops_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut band: Band<4, _> = Band::new(tracked_source(&drops, 10));
+        band.expand_n(4);
+
+        let band: Band<2, _> = band.resize();
+        assert_eq!(drops.get(), 2);
+
+        // remaining 2 items and the source are dropped with the band
+        drop(band);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn converts_into_tape() {
+        let mut band: Band<3, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(4);
+        assert!(!band.expand_while(|item| *item > 5));
+
+        let mut tape = band.into_tape();
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        // the item rejected by `expand_while` is not lost
+        assert!(tape.expand_n(2));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn expands_step_by() {
+        let mut band: Band<5, _> = Band::new(0..20);
+
+        assert!(band.expand_step_by(5, 2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &2, &4, &6, &8]);
+
+        // the oldest items are dropped to make room
+        assert!(band.expand_step_by(2, 3));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6, &8, &9, &12]);
+
+        // nothing is skipped if the item cannot be appended
+        let mut band: Band<2, _> = Band::new_bounded(0..20);
+        assert!(band.expand_step_by(5, 2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &2]);
+
+        band.clear();
+        assert!(band.expand());
+        assert_eq!(band.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn debug_prints_logical_order() {
+        let mut band: Band<3, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(5);
+        assert_eq!(band.as_slices(), (&[2][..], &[3, 4][..]));
+
+        let debug = format!("{band:?}");
+        assert!(debug.starts_with("Band { items: [2, 3, 4], capacity: 3, overflow: DropOldest"));
+
+        band.clear();
+        assert!(format!("{band:?}").starts_with("Band { items: [], capacity: 3"));
+    }
+
+    #[test]
+    fn debug_without_debug_iterator() {
+        struct Counter(u32);
+
+        impl Iterator for Counter {
+            type Item = u32;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0 += 1;
+                Some(self.0)
+            }
+        }
+
+        let mut band: Band<2, _> = Band::new(Counter(0));
+        band.expand();
+
+        assert_eq!(
+            format!("{band:?}"),
+            "Band { items: [1], capacity: 2, overflow: DropOldest, .. }"
+        );
+    }
+
+    #[test]
+    fn swap_removes() {
+        let mut band: Band<4, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5]);
+
+        assert_eq!(band.swap_remove(0), Some(2));
+        assert_eq!(band.len(), 3);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &3, &4]);
+
+        assert_eq!(band.swap_remove(3), None);
+        assert_eq!(band.swap_remove(2), Some(4));
+        assert_eq!(band.len(), 2);
+
+        band.clear();
+        assert_eq!(band.swap_remove(0), None);
+    }
+
+    #[test]
+    fn eq_slices() {
+        let mut band: Band<4, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+        assert_eq!(band, [2, 3, 4, 5]);
+        assert_eq!(band, &[2, 3, 4, 5][..]);
+        #[cfg(feature = "alloc")]
+        assert_eq!(band, vec![2, 3, 4, 5]);
+
+        assert_ne!(band, [2, 3, 4]);
+        assert_ne!(band, [2, 3, 5, 4]);
+
+        band.clear();
+        assert_eq!(band, [] as [i32; 0]);
+    }
+
+    #[test]
+    fn gets_disjoint_mut() {
+        let mut band: Band<4, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+        for (i, j) in [(0, 1), (3, 2), (1, 2), (3, 0)] {
+            let (a, b) = band.get_disjoint_mut(i, j).unwrap();
+            *a *= 10;
+            *b += 1;
+        }
+
+        assert_eq!(band.peek_at(0), Some(&21));
+        assert_eq!(band.peek_at(1), Some(&40));
+        assert_eq!(band.peek_at(2), Some(&6));
+        assert_eq!(band.peek_at(3), Some(&500));
+
+        assert_eq!(band.get_disjoint_mut(2, 2), None);
+        assert_eq!(band.get_disjoint_mut(0, 4), None);
+
+        band.pop_back();
+        assert_eq!(band.get_disjoint_mut(0, 3), None);
+    }
+
+    #[test]
+    fn clones_ends() {
+        let mut band: Band<3, _> = Band::new(0..10);
+        assert_eq!(band.front_cloned(), None);
+
+        // wrap around the end of the inner array
+        band.expand_n(5);
+        assert_eq!(band.front_cloned(), Some(2));
+        assert_eq!(band.back_cloned(), Some(4));
+
+        if let Some(front) = band.peek_front_mut() {
+            *front = 42;
+        }
+
+        assert_eq!(band.front_cloned(), Some(42));
+    }
+
+    #[test]
+    fn rpositions() {
+        let mut band: Band<4, _> = Band::new([0, 1, 2, 1, 2, 1, 3].into_iter());
+
+        // wrap around the end of the inner array
+        band.expand_n(6);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &1, &2, &1]);
+
+        assert_eq!(band.position(|item| *item == 2), Some(0));
+        assert_eq!(band.rposition(|item| *item == 2), Some(2));
+        assert_eq!(band.position(|item| *item == 1), Some(1));
+        assert_eq!(band.rposition(|item| *item == 1), Some(3));
+        assert_eq!(band.rposition(|item| *item == 0), None);
+    }
+
+    #[test]
+    fn presets() {
+        let mut band: Band8<_> = (0..100).band();
+        band.expand_n(100);
+        assert_eq!(band.len(), 8);
+        assert_eq!(band.peek_front(), Some(&92));
+
+        let band: Band16<_> = Band16::new(0..100);
+        assert_eq!(band.capacity(), 16);
+
+        let mut band: Band32<_> = (0..100).into_band();
+        assert!(band.fill());
+        assert_eq!(band.peek_back(), Some(&31));
+    }
+
+    #[test]
+    fn is_full() {
+        let mut band: Band<3, _> = Band::new(0..10);
+        assert!(!band.is_full());
+
+        band.expand_n(3);
+        assert!(band.is_full());
+
+        band.pop_back();
+        assert!(!band.is_full());
+
+        // through a trait object
+        let mut band: Band<1, _> = Band::new(0..10);
+        band.expand();
+        let band: &dyn Ribbon<i32> = &band;
+        assert!(band.is_full());
+    }
+
+    #[test]
+    fn saturating_expands() {
+        let mut band: Band<3, _> = Band::new(0..10);
+
+        assert_eq!(band.saturating_expand_n(10), 3);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+
+        band.pop_front();
+        assert_eq!(band.saturating_expand_n(10), 1);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        // nothing is pulled from the iterator while full
+        assert_eq!(band.saturating_expand_n(10), 0);
+        band.clear();
+        assert_eq!(band.saturating_expand_n(2), 2);
+        assert_eq!(band.peek_front(), Some(&4));
+    }
+
+    #[test]
+    fn peeks_at_or_default() {
+        let mut band: Band<3, _> = Band::new(0..10);
+
+        // wrap around the end of the inner array
+        band.expand_n(5);
+        assert_eq!(band.peek_at_or(0, &-1), &2);
+        assert_eq!(band.peek_at_or(2, &-1), &4);
+        assert_eq!(band.peek_at_or(3, &-1), &-1);
+    }
+
+    #[test]
+    fn does_not_poll_exhausted_source() {
+        /// Iterator producing `0..3` that counts how often it is polled.
+        struct Counting<'a>(&'a Cell<usize>);
+
+        impl Iterator for Counting<'_> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let polls = self.0.get();
+                self.0.set(polls + 1);
+                (polls < 3).then_some(polls)
+            }
+        }
+
+        let polls = Cell::new(0);
+        let mut band: Band<2, _> = Band::new(Counting(&polls));
+
+        assert_eq!(band.by_ref().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(polls.get(), 4);
+
+        for _ in 0..5 {
+            assert_eq!(band.next(), None);
+        }
+
+        assert_eq!(polls.get(), 4);
+    }
+
+    #[test]
+    fn seals() {
+        let polls = Cell::new(0);
+        let source = (0..10).inspect(|_| polls.set(polls.get() + 1));
+
+        // wrap around the end of the inner array
+        let mut band: Band<3, _> = Band::new(source);
+        band.expand_n(4);
+        band.seal();
+        assert_eq!(polls.get(), 4);
+
+        assert!(!band.expand());
+        assert_eq!(band.progress(), None);
+        assert_eq!(polls.get(), 4);
+
+        // buffered items remain accessible
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(band.pop_front(), Some(1));
+        assert!(!band.fill());
+        assert_eq!(band.by_ref().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(band.next(), None);
+        assert_eq!(polls.get(), 4);
+    }
+
     #[test]
     fn is_iterator() {
         let mut band = (0..10).band::<5>();
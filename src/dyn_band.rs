@@ -0,0 +1,732 @@
+//! Implementation of a fix-sized data structure with capacity chosen at runtime, that implements
+//! the [`Ribbon`] trait.
+//!
+//! [`Ribbon`]: crate::Ribbon
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{ribbon, source::Source, Ribbon};
+
+/// A fix-sized [`Ribbon`] like [`Band`], except that its capacity is chosen at runtime rather than
+/// at compile time. It is backed up by a boxed slice allocated on the heap once, when the `DynBand`
+/// is created. It cannot grow over the given capacity, and instead drops and/or returns items if
+/// no space is available at the given moment.
+///
+/// # Example
+///
+/// ```rust
+/// use ribbon::{DynBand, Ribbon};
+///
+/// let capacity = 3;
+/// let mut band = DynBand::new(0..10, capacity);
+///
+/// band.expand_n(5);
+/// assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+/// ```
+///
+/// [`Ribbon`]: crate::Ribbon
+/// [`Band`]: crate::Band
+#[derive(Debug)]
+pub struct DynBand<I>
+where
+    I: Iterator,
+{
+    iter: Source<I>,
+    /// Slots at logical indices `0..len`, starting at `head` and wrapping around, are `Some`.
+    tape: Box<[Option<I::Item>]>,
+    head: usize,
+    len: usize,
+}
+
+impl<I> DynBand<I>
+where
+    I: Iterator,
+{
+    /// Creates a new `DynBand` from the given iterator, that holds at most `capacity` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero, since a `DynBand` without capacity cannot hold any items.
+    pub fn new(iter: I, capacity: usize) -> DynBand<I> {
+        assert!(capacity > 0, "`DynBand` must have a non-zero capacity");
+
+        DynBand {
+            iter: Source::new(iter),
+            tape: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Consumes the `DynBand` and returns the wrapped iterator. The returned iterator continues
+    /// from where the `DynBand` left off. Items currently found on the `DynBand` are dropped.
+    pub fn into_inner(self) -> I {
+        self.iter.into_parts().1
+    }
+
+    /// Expands the `DynBand` until it is full, or the iterator stops producing values. Returns
+    /// `true` if the `DynBand` is full afterwards.
+    pub fn fill(&mut self) -> bool {
+        while !self.is_full() && self.expand() {}
+
+        self.is_full()
+    }
+
+    /// Converts the logical index into the index of the inner slice.
+    fn physical(&self, index: usize) -> usize {
+        (self.head + index) % self.tape.len()
+    }
+
+    /// Removes and returns the head of the `DynBand`, moving the head index past it.
+    fn slide(&mut self) -> Option<I::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let first = self.tape[self.head].take();
+        self.head = self.physical(1);
+        self.len -= 1;
+
+        first
+    }
+
+    /// Appends the item to the tail of the `DynBand`. The `DynBand` must not be full.
+    fn push_back_unchecked(&mut self, item: I::Item) {
+        debug_assert!(!self.is_full());
+
+        let tail = self.physical(self.len);
+        self.tape[tail] = Some(item);
+        self.len += 1;
+    }
+
+    /// Prepends the item to the head of the `DynBand`. The `DynBand` must not be full.
+    fn push_front_unchecked(&mut self, item: I::Item) {
+        debug_assert!(!self.is_full());
+
+        self.head = self.physical(self.tape.len() - 1);
+        self.tape[self.head] = Some(item);
+        self.len += 1;
+    }
+
+    /// Returns the ranges of the inner slice, as `(start, end)` pairs, that hold the items of the
+    /// `DynBand` from head up to the end of the slice, and from the start of the slice respectively.
+    fn split_occupied(&self) -> ((usize, usize), (usize, usize)) {
+        let end = self.head + self.len;
+        let cap = self.tape.len();
+
+        if end > cap {
+            ((self.head, cap), (0, end - cap))
+        } else {
+            ((self.head, end), (0, 0))
+        }
+    }
+}
+
+impl<I> ribbon::Ribbon<I::Item> for DynBand<I>
+where
+    I: Iterator,
+{
+    /// Streams the iterator forward through the `DynBand` without expanding it. Underlying
+    /// iterator is polled for the next item, which is appended to the tail. The head of the
+    /// `DynBand` is removed and returned.
+    ///
+    /// Is a no-op if iterator stops producing values. In that case `None` is returned.
+    fn progress(&mut self) -> Option<I::Item> {
+        let next = self.iter.next()?;
+
+        let head = self.slide();
+        self.push_back_unchecked(next);
+
+        head
+    }
+
+    /// Expands the `DynBand` by consuming the next available item and appending it to the end.
+    /// Drops the first element if the `DynBand` is already at full capacity.
+    fn expand(&mut self) -> bool {
+        let Some(item) = self.iter.next() else {
+            return false;
+        };
+
+        if self.is_full() {
+            self.slide();
+        }
+
+        self.push_back_unchecked(item);
+        true
+    }
+
+    fn expand_step_by(&mut self, n: usize, step: usize) -> bool {
+        assert!(step != 0, "step must be non-zero");
+
+        let mut expanded = false;
+
+        for i in 0..n {
+            if i > 0 {
+                self.iter.discard(step - 1);
+            }
+
+            if !self.expand() {
+                break;
+            }
+
+            expanded = true;
+        }
+
+        expanded
+    }
+
+    fn expand_while_indexed<F>(&mut self, f: F) -> bool
+    where
+        F: Fn(usize, &I::Item) -> bool,
+    {
+        let mut count = 0;
+
+        loop {
+            match self.iter.peek() {
+                Some(item) if f(count, item) => {
+                    count += 1;
+                    self.expand();
+                }
+                _ => break,
+            }
+        }
+
+        count > 0
+    }
+
+    fn expand_while_peek<F>(&mut self, f: F) -> (bool, Option<&I::Item>)
+    where
+        F: Fn(&I::Item) -> bool,
+    {
+        let expanded = self.expand_while(f);
+        (expanded, self.iter.peek())
+    }
+
+    fn source_exhausted(&mut self) -> bool {
+        self.iter.peek().is_none()
+    }
+
+    fn seal(&mut self) {
+        self.iter.seal();
+    }
+
+    fn expand_take(&mut self, n: usize) {
+        self.iter.limit(n);
+    }
+
+    fn pop_front(&mut self) -> Option<I::Item> {
+        self.slide()
+    }
+
+    fn peek_front_mut(&mut self) -> Option<&mut I::Item> {
+        self.peek_at_mut(0)
+    }
+
+    fn pop_back(&mut self) -> Option<I::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        let tail = self.physical(self.len);
+        self.tape[tail].take()
+    }
+
+    fn peek_back_mut(&mut self) -> Option<&mut I::Item> {
+        self.peek_at_mut(self.len.checked_sub(1)?)
+    }
+
+    fn peek_at(&self, index: usize) -> Option<&I::Item> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.tape[self.physical(index)].as_ref()
+    }
+
+    fn peek_at_mut(&mut self, index: usize) -> Option<&mut I::Item> {
+        if index >= self.len {
+            return None;
+        }
+
+        let idx = self.physical(index);
+        self.tape[idx].as_mut()
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a I::Item>
+    where
+        I::Item: 'a,
+    {
+        let (first, second) = self.split_occupied();
+
+        self.tape[first.0..first.1]
+            .iter()
+            .chain(&self.tape[second.0..second.1])
+            .flatten()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut I::Item>
+    where
+        I::Item: 'a,
+    {
+        let (first, second) = self.split_occupied();
+        let (wrapped, head) = self.tape.split_at_mut(first.0);
+
+        head[..first.1 - first.0]
+            .iter_mut()
+            .chain(&mut wrapped[second.0..second.1])
+            .flatten()
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len;
+        assert!(
+            i < len,
+            "index out of bounds: the len is {len} but the index is {i}"
+        );
+        assert!(
+            j < len,
+            "index out of bounds: the len is {len} but the index is {j}"
+        );
+
+        let (i, j) = (self.physical(i), self.physical(j));
+        self.tape.swap(i, j);
+    }
+
+    /// Inserts the value at the given index of the `DynBand`, shifting all items after it towards
+    /// the tail. If the `DynBand` is full, the value is inserted and the head of the `DynBand` is
+    /// removed and returned. If the value is inserted at the head, it is therefore returned right
+    /// away.
+    fn insert_at(&mut self, index: usize, value: I::Item) -> Option<I::Item> {
+        let len = self.len;
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if self.is_full() {
+            if index == 0 {
+                return Some(value);
+            }
+
+            let head = self.slide();
+            self.insert_at(index - 1, value);
+            return head;
+        }
+
+        self.push_back_unchecked(value);
+
+        for i in (index..len).rev() {
+            let (i, j) = (self.physical(i), self.physical(i + 1));
+            self.tape.swap(i, j);
+        }
+
+        None
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<I::Item> {
+        if index >= self.len {
+            return None;
+        }
+
+        // move the item to the tail, so it can be removed from there
+        for i in index..self.len - 1 {
+            let (i, j) = (self.physical(i), self.physical(i + 1));
+            self.tape.swap(i, j);
+        }
+
+        self.pop_back()
+    }
+
+    fn get_disjoint_mut(&mut self, i: usize, j: usize) -> Option<(&mut I::Item, &mut I::Item)> {
+        if i >= self.len || j >= self.len {
+            return None;
+        }
+
+        let (i, j) = (self.physical(i), self.physical(j));
+        let (a, b) = ribbon::get_pair_mut(&mut self.tape, i, j)?;
+
+        Some((a.as_mut()?, b.as_mut()?))
+    }
+
+    fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation is larger than the length of the `DynBand`"
+        );
+
+        if self.is_full() {
+            self.head = self.physical(n);
+            return;
+        }
+
+        for _ in 0..n {
+            if let Some(item) = self.slide() {
+                self.push_back_unchecked(item);
+            }
+        }
+    }
+
+    fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation is larger than the length of the `DynBand`"
+        );
+
+        if self.is_full() {
+            self.head = self.physical(self.len - n);
+            return;
+        }
+
+        for _ in 0..n {
+            if let Some(item) = self.pop_back() {
+                self.push_front_unchecked(item);
+            }
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop_back();
+        }
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&I::Item) -> bool,
+    {
+        // the length is reset first, so that a panic in `f` leaves the `DynBand` consistent
+        let len = self.len;
+        self.len = 0;
+
+        let mut retained = 0;
+
+        for index in 0..len {
+            let idx = self.physical(index);
+
+            if let Some(item) = self.tape[idx].take().filter(&mut f) {
+                let idx = self.physical(retained);
+                self.tape[idx] = Some(item);
+                retained += 1;
+            }
+        }
+
+        self.len = retained;
+    }
+
+    fn dedup_buffered(&mut self)
+    where
+        I::Item: PartialEq,
+    {
+        // the length is reset first, so that a panic in `eq` leaves the `DynBand` consistent
+        let len = self.len;
+        self.len = 0;
+
+        let mut retained = 0;
+
+        for index in 0..len {
+            let idx = self.physical(index);
+            let item = self.tape[idx].take();
+
+            let duplicate = retained > 0 && self.tape[self.physical(retained - 1)] == item;
+
+            if !duplicate {
+                let idx = self.physical(retained);
+                self.tape[idx] = item;
+                retained += 1;
+            }
+        }
+
+        self.len = retained;
+    }
+
+    fn clear(&mut self) {
+        self.tape.iter_mut().for_each(|slot| *slot = None);
+        self.head = 0;
+        self.len = 0;
+    }
+
+    fn capacity(&self) -> usize {
+        self.tape.len()
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == self.tape.len()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<I> Clone for DynBand<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut tape: Vec<_> = self.iter().cloned().map(Some).collect();
+        tape.resize_with(self.tape.len(), || None);
+
+        Self {
+            iter: self.iter.clone(),
+            tape: tape.into_boxed_slice(),
+            head: 0,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynBand;
+    use crate::{Enroll, Ribbon};
+
+    #[test]
+    fn expands() {
+        let mut band = DynBand::new(0..10, 3);
+        assert_eq!(band.capacity(), 3);
+
+        assert!(band.expand_n(2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+
+        // wrap around the end of the inner slice, dropping the oldest items
+        assert!(band.expand_n(3));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(band.len(), 3);
+        assert_eq!(band.peek_front(), Some(&2));
+        assert_eq!(band.peek_back(), Some(&4));
+    }
+
+    #[test]
+    fn makes_progress() {
+        let mut band = DynBand::new(0..5, 5);
+
+        // band was empty, first progress has nothing to return
+        assert_eq!(band.progress(), None);
+
+        assert_eq!(band.progress(), Some(0));
+        assert_eq!(band.progress(), Some(1));
+        assert_eq!(band.progress(), Some(2));
+        assert_eq!(band.progress(), Some(3));
+
+        // iterator does not produce more values, so progress does not drop anything
+        assert_eq!(band.progress(), None);
+        assert_eq!(band.len(), 1);
+    }
+
+    #[test]
+    fn progresses_full() {
+        let mut band = (0..10).dyn_band(3);
+        assert!(band.fill());
+
+        assert_eq!(band.progress(), Some(0));
+        assert_eq!(band.progress(), Some(1));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+    }
+
+    #[test]
+    fn pops() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(4);
+
+        assert_eq!(band.pop_back(), Some(3));
+        assert_eq!(band.pop_front(), Some(1));
+        assert_eq!(band.pop_front(), Some(2));
+        assert_eq!(band.pop_front(), None);
+        assert_eq!(band.pop_back(), None);
+        assert_eq!(band.peek_front(), None);
+        assert_eq!(band.peek_back(), None);
+
+        // can be expanded again after popping everything
+        assert!(band.expand_n(3));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+    }
+
+    #[test]
+    fn peeks() {
+        let mut band = DynBand::new(0..10, 4);
+        band.expand_n(6);
+
+        assert_eq!(band.peek_at(0), Some(&2));
+        assert_eq!(band.peek_at(3), Some(&5));
+        assert_eq!(band.peek_at(4), None);
+
+        if let Some(item) = band.peek_at_mut(1) {
+            *item = 42;
+        }
+        if let Some(item) = band.peek_back_mut() {
+            *item = 43;
+        }
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &42, &4, &43]);
+
+        band.iter_mut().for_each(|item| *item += 1);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &43, &5, &44]);
+    }
+
+    #[test]
+    fn expands_while() {
+        let mut band = DynBand::new(0..10, 4);
+
+        assert!(band.expand_while(|item| *item < 6));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5]);
+        assert!(!band.expand_while(|item| *item < 6));
+        assert!(!band.source_exhausted());
+
+        assert_eq!(band.expand_while_peek(|item| *item < 8), (true, Some(&8)));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &5, &6, &7]);
+    }
+
+    #[test]
+    fn rotates() {
+        let mut band = DynBand::new(0..10, 4);
+        band.expand_n(5);
+
+        band.rotate_left(1);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &1]);
+        band.rotate_right(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &1, &2, &3]);
+
+        // not full
+        band.pop_front();
+        band.rotate_left(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &1, &2]);
+        band.rotate_right(1);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &3, &1]);
+    }
+
+    #[test]
+    fn swaps_and_truncates() {
+        let mut band = DynBand::new(0..10, 4);
+        band.expand_n(6);
+
+        band.swap(0, 3);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &3, &4, &2]);
+
+        band.truncate(2);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&5, &3]);
+    }
+
+    #[test]
+    fn inserts_at() {
+        let mut band = DynBand::new(0..10, 4);
+        band.expand_n(5);
+        band.pop_front();
+
+        assert_eq!(band.insert_at(1, 10), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &10, &3, &4]);
+
+        assert_eq!(band.insert_at(4, 11), Some(2));
+        assert_eq!(band.insert_at(0, 12), Some(12));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&10, &3, &4, &11]);
+    }
+
+    #[test]
+    fn expands_step_by() {
+        let mut band = DynBand::new(0..20, 3);
+
+        assert!(band.expand_step_by(5, 2));
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&4, &6, &8]);
+    }
+
+    #[test]
+    fn gets_disjoint_mut() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(4);
+
+        let (a, b) = band.get_disjoint_mut(2, 0).unwrap();
+        core::mem::swap(a, b);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+
+        assert_eq!(band.get_disjoint_mut(1, 1), None);
+        assert_eq!(band.get_disjoint_mut(1, 3), None);
+    }
+
+    #[test]
+    fn seals() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(2);
+        band.seal();
+
+        assert!(!band.expand());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+    }
+
+    #[test]
+    fn expand_takes() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_take(2);
+
+        assert!(band.expand());
+        assert!(band.expand_n(3));
+        assert!(!band.expand());
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+    }
+
+    #[test]
+    fn removes_at() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(4);
+
+        assert_eq!(band.remove_at(1), Some(2));
+        assert_eq!(band.remove_at(2), None);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &3]);
+    }
+
+    #[test]
+    fn retains_and_dedups() {
+        let mut band = DynBand::new([0, 1, 1, 2, 3, 3, 4].into_iter(), 6);
+        band.expand_n(7);
+
+        band.dedup_buffered();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+
+        band.retain(|item| item % 2 == 0);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&2, &4]);
+        assert_eq!(band.len(), 2);
+    }
+
+    #[test]
+    fn clears() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(4);
+
+        band.clear();
+        assert!(band.is_empty());
+        assert_eq!(band.iter().count(), 0);
+
+        band.expand();
+        assert_eq!(band.peek_front(), Some(&4));
+    }
+
+    #[test]
+    fn clones() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(4);
+
+        let mut clone = band.clone();
+        assert!(clone.content_eq(&band));
+
+        clone.expand();
+        assert_eq!(clone.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn into_inner_continues() {
+        let mut band = DynBand::new(0..10, 3);
+        band.expand_n(3);
+        band.progress();
+
+        assert_eq!(
+            band.into_inner().collect::<Vec<_>>(),
+            vec![4, 5, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero capacity")]
+    fn rejects_zero_capacity() {
+        let _band = DynBand::new(0..10, 0);
+    }
+}
@@ -18,11 +18,15 @@
 //!   grow over the given fixed length, and instead drops the first element if no
 //!   space is available at the given moment.
 //!
+//! The crate is `no_std`. [`Tape`] requires the `alloc` feature, which is enabled by default.
+//! [`Band`], [`Ribbon`] and [`Enroll::band`] are available without it.
+//!
 //! ## Examples:
 //!
 //! ### Using `Tape`
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use ribbon::{Ribbon, Tape, Enroll};
 //!
 //! let mut tape = (0..10).tape();
@@ -31,6 +35,7 @@
 //! assert_eq!(tape.len(), 5);
 //! assert_eq!(tape.peek_front(), Some(&0));
 //! assert_eq!(tape.peek_back(), Some(&4));
+//! # }
 //! ```
 //!
 //! ### Using `Band`
@@ -54,13 +59,35 @@
 //! assert_eq!(band.progress(), None);
 //! ```
 //!
-//! [`VecDeque`]: std::collections::VecDeque
+//! [`VecDeque`]: alloc::collections::VecDeque
+
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod ribbon;
+mod source;
 
+pub mod adapters;
 pub mod band;
+#[cfg(feature = "alloc")]
+pub mod dyn_band;
+#[cfg(feature = "alloc")]
+pub mod replay;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "alloc")]
 pub mod tape;
+pub mod view;
 
+pub use adapters::*;
 pub use band::*;
+#[cfg(feature = "alloc")]
+pub use dyn_band::*;
+#[cfg(feature = "alloc")]
+pub use replay::*;
 pub use ribbon::*;
+#[cfg(feature = "alloc")]
 pub use tape::*;
+pub use view::*;
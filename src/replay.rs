@@ -0,0 +1,223 @@
+//! Implementation of a dynamically sized buffer that retains consumed items, so that they can be
+//! read again.
+
+use alloc::collections::VecDeque;
+
+use crate::source::Source;
+
+/// A dynamically sized buffer over an iterator that retains the items it has handed out. Reading an
+/// item moves a cursor forward rather than removing the item, so the cursor can be moved back with
+/// [`rewind`] or [`reset`] to read the items again. This makes it a backtracking buffer, e.g. for
+/// PEG or packrat parsers.
+///
+/// Consumed items are retained until [`commit`] is called, which discards every item before the
+/// cursor. Memory usage therefore grows with the number of items read since the last commit, so
+/// commit whenever backtracking past the current position is no longer needed.
+///
+/// # Example
+///
+/// ```rust
+/// use ribbon::ReplayTape;
+///
+/// let mut tape = ReplayTape::new("abc".chars());
+///
+/// assert_eq!(tape.read(), Some(&'a'));
+/// assert_eq!(tape.read(), Some(&'b'));
+///
+/// assert!(tape.rewind(1));
+/// assert_eq!(tape.read(), Some(&'b'));
+///
+/// tape.commit();
+/// assert!(!tape.rewind(1));
+/// assert_eq!(tape.read(), Some(&'c'));
+/// ```
+///
+/// [`rewind`]: ReplayTape::rewind
+/// [`reset`]: ReplayTape::reset
+/// [`commit`]: ReplayTape::commit
+#[derive(Debug)]
+pub struct ReplayTape<I>
+where
+    I: Iterator,
+{
+    iter: Source<I>,
+    /// Items before the cursor were consumed, items at and after it were not read yet.
+    tape: VecDeque<I::Item>,
+    cursor: usize,
+}
+
+impl<I> ReplayTape<I>
+where
+    I: Iterator,
+{
+    /// Creates a new `ReplayTape` from the given iterator.
+    pub fn new(iter: I) -> ReplayTape<I> {
+        ReplayTape {
+            iter: Source::new(iter),
+            tape: VecDeque::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Reads the next item and moves the cursor past it. The item is pulled from the iterator if
+    /// all buffered items were already read. Returns `None` if the iterator is exhausted.
+    pub fn read(&mut self) -> Option<&I::Item> {
+        if self.cursor == self.tape.len() && !self.expand() {
+            return None;
+        }
+
+        self.cursor += 1;
+        self.tape.get(self.cursor - 1)
+    }
+
+    /// Returns a reference to the next item without moving the cursor. Returns `None` if all
+    /// buffered items were already read. The iterator is not polled.
+    pub fn peek(&self) -> Option<&I::Item> {
+        self.tape.get(self.cursor)
+    }
+
+    /// Pulls the next item from the iterator and appends it to the end of the buffer, after the
+    /// items that were not read yet. Returns `false` if the iterator is exhausted.
+    pub fn expand(&mut self) -> bool {
+        match self.iter.next() {
+            Some(item) => {
+                self.tape.push_back(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor back by `n` items, so that they are read again. Returns `false` and leaves
+    /// the cursor untouched if fewer than `n` consumed items are retained, e.g. because they were
+    /// discarded by [`commit`].
+    ///
+    /// [`commit`]: ReplayTape::commit
+    pub fn rewind(&mut self, n: usize) -> bool {
+        match self.cursor.checked_sub(n) {
+            Some(cursor) => {
+                self.cursor = cursor;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor back to the oldest retained item, so that all retained items are read
+    /// again.
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Discards all consumed items, so that they cannot be read again. Frees up the memory held by
+    /// them.
+    pub fn commit(&mut self) {
+        self.tape.drain(..self.cursor);
+        self.cursor = 0;
+    }
+
+    /// Returns the number of consumed items that are retained, i.e. how far the cursor can be
+    /// rewound.
+    pub fn consumed(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the number of buffered items that were not read yet.
+    pub fn len(&self) -> usize {
+        self.tape.len() - self.cursor
+    }
+
+    /// Returns `true` if all buffered items were already read.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Consumes the `ReplayTape` and returns the wrapped iterator. The returned iterator continues
+    /// from where the `ReplayTape` left off. Buffered items are dropped.
+    pub fn into_inner(self) -> I {
+        self.iter.into_parts().1
+    }
+}
+
+impl<I> Clone for ReplayTape<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            tape: self.tape.clone(),
+            cursor: self.cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplayTape;
+
+    #[test]
+    fn reads() {
+        let mut tape = ReplayTape::new(0..3);
+
+        assert_eq!(tape.read(), Some(&0));
+        assert_eq!(tape.read(), Some(&1));
+        assert_eq!(tape.read(), Some(&2));
+        assert_eq!(tape.read(), None);
+        assert_eq!(tape.consumed(), 3);
+        assert!(tape.is_empty());
+    }
+
+    #[test]
+    fn rewinds_and_rereads() {
+        let mut tape = ReplayTape::new(0..10);
+        tape.read();
+        tape.read();
+        tape.read();
+
+        assert!(tape.rewind(2));
+        assert_eq!(tape.len(), 2);
+        assert_eq!(tape.peek(), Some(&1));
+        assert_eq!(tape.read(), Some(&1));
+        assert_eq!(tape.read(), Some(&2));
+        assert_eq!(tape.read(), Some(&3));
+
+        tape.reset();
+        assert_eq!(tape.consumed(), 0);
+        assert_eq!(tape.read(), Some(&0));
+
+        // cannot rewind past the oldest retained item
+        assert!(!tape.rewind(2));
+        assert_eq!(tape.consumed(), 1);
+    }
+
+    #[test]
+    fn commit_discards_consumed() {
+        let mut tape = ReplayTape::new(0..10);
+        tape.read();
+        tape.read();
+        tape.expand();
+
+        tape.commit();
+        assert_eq!(tape.consumed(), 0);
+        assert_eq!(tape.len(), 1);
+        assert!(!tape.rewind(1));
+
+        assert_eq!(tape.read(), Some(&2));
+        assert!(tape.rewind(1));
+        assert_eq!(tape.read(), Some(&2));
+
+        tape.reset();
+        assert_eq!(tape.read(), Some(&2));
+    }
+
+    #[test]
+    fn into_inner_continues() {
+        let mut tape = ReplayTape::new(0..5);
+        tape.read();
+        tape.expand();
+
+        assert_eq!(tape.into_inner().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+}
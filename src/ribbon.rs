@@ -1,5 +1,20 @@
-use crate::{Band, Tape};
+use core::{
+    iter::Sum,
+    ops::{Bound, RangeBounds},
+};
 
+use crate::{adapters::Drain, Band, Windows, ZipRibbon};
+#[cfg(feature = "alloc")]
+use crate::{Chunks, DynBand, Tape};
+
+/// Buffer over an iterator, that can be expanded with items pulled from the iterator, and consumed
+/// from both ends.
+///
+/// The trait can be used as a trait object, e.g. through [`DynRibbon`] to store ribbons of
+/// different types together. Methods that are generic or return `impl Iterator` are not available
+/// on trait objects.
+///
+/// [`DynRibbon`]: crate::DynRibbon
 pub trait Ribbon<T> {
     /// Tries to stream the iterator forward through the `Ribbon` without expanding it. Underlying
     /// iterator is polled for the next element. Returns the head of the `Ribbon`, and the new item
@@ -10,6 +25,7 @@ pub trait Ribbon<T> {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
@@ -21,15 +37,94 @@ pub trait Ribbon<T> {
     /// assert_eq!(tape.len(), 5);
     /// assert_eq!(tape.peek_front(), Some(&1));
     /// assert_eq!(tape.peek_back(), Some(&5));
+    /// # }
     /// ```
     fn progress(&mut self) -> Option<T>;
 
+    /// Streams the iterator forward through the `Ribbon` by `n` items, as if [`progress`] was
+    /// called `n` times. Returns an iterator over the heads of the `Ribbon` that were removed in the
+    /// process, in order.
+    ///
+    /// The `Ribbon` is progressed lazily, as the returned iterator is consumed. The returned
+    /// iterator stops early once [`progress`] returns `None`, i.e. when the underlying iterator
+    /// stops producing values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// let items: Vec<_> = tape.progress_n(3).collect();
+    ///
+    /// assert_eq!(items, vec![0, 1, 2]);
+    /// assert_eq!(tape.len(), 5);
+    /// assert_eq!(tape.peek_front(), Some(&3));
+    /// assert_eq!(tape.peek_back(), Some(&7));
+    /// # }
+    /// ```
+    ///
+    /// [`progress`]: Ribbon::progress
+    fn progress_n(&mut self, n: usize) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+    {
+        (0..n).map_while(move |_| self.progress())
+    }
+
+    /// Streams the iterator forward through the `Ribbon` while the head of the `Ribbon` satisfies
+    /// the given condition. Returns `true` if the `Ribbon` progressed by at least one item.
+    ///
+    /// The condition is checked against the current head *before* each call to [`progress`], so
+    /// the removed heads are exactly the items for which the condition holds. Stops once the
+    /// condition fails for the head, or the underlying iterator stops producing values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("   let".chars());
+    ///
+    /// tape.expand_n(3);
+    /// assert!(tape.progress_while(|c| c.is_whitespace()));
+    /// assert_eq!(tape.iter().collect::<String>(), "let");
+    ///
+    /// // head does not satisfy the condition, nothing happens
+    /// assert_eq!(tape.progress_while(|c| c.is_whitespace()), false);
+    /// # }
+    /// ```
+    ///
+    /// [`progress`]: Ribbon::progress
+    fn progress_while<F>(&mut self, f: F) -> bool
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        let mut progressed = false;
+
+        while self.peek_front().is_some_and(&f) {
+            if self.progress().is_none() {
+                break;
+            }
+
+            progressed = true;
+        }
+
+        progressed
+    }
+
     /// Expands the `Ribbon` by consuming the next available item and appending it to the tail.
     /// Returns `true` if `Ribbon` is expanded.
     ///
     /// # Example
     ///
     ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..2);
@@ -46,6 +141,7 @@ pub trait Ribbon<T> {
     ///
     /// // no more elements, expansion fails
     /// assert_eq!(tape.expand(), false);
+    ///# }
     /// ```
     fn expand(&mut self) -> bool;
 
@@ -55,6 +151,7 @@ pub trait Ribbon<T> {
     /// # Example
     ///
     ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
@@ -71,6 +168,7 @@ pub trait Ribbon<T> {
     ///
     /// // not expanding anymore, returns false
     /// assert_eq!(tape.expand_n(1), false);
+    ///# }
     /// ```
     fn expand_n(&mut self, n: usize) -> bool {
         let mut expanded = false;
@@ -85,12 +183,89 @@ pub trait Ribbon<T> {
         expanded
     }
 
+    /// Expands the `Ribbon` by at most `n` items, like [`expand_n`]. Returns the number of items
+    /// that were actually appended, which is less than `n` if the underlying iterator stopped
+    /// producing values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// assert_eq!(tape.expand_n_counted(4), 4);
+    /// assert_eq!(tape.expand_n_counted(8), 6);
+    /// assert_eq!(tape.expand_n_counted(1), 0);
+    /// assert_eq!(tape.len(), 10);
+    /// # }
+    /// ```
+    ///
+    /// [`expand_n`]: Ribbon::expand_n
+    fn expand_n_counted(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.expand()).count()
+    }
+
+    /// Expands the `Ribbon` by up to `n` items, but only as long as it is not full, so that no
+    /// item is ever dropped to make room. Returns the number of items appended.
+    ///
+    /// For a `Ribbon` that is never full, this is the same as [`expand_n_counted`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon};
+    ///
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    ///
+    /// assert_eq!(band.saturating_expand_n(10), 3);
+    /// assert_eq!(band.saturating_expand_n(10), 0);
+    /// assert_eq!(band.peek_front(), Some(&0));
+    /// ```
+    ///
+    /// [`expand_n_counted`]: Ribbon::expand_n_counted
+    fn saturating_expand_n(&mut self, n: usize) -> usize {
+        (0..n)
+            .take_while(|_| !self.is_full() && self.expand())
+            .count()
+    }
+
+    /// Expands the `Ribbon` by `n` items, keeping only every `step`-th item of the iterator and
+    /// appending it to the end. Returns `true` if `Ribbon` is expanded by at least one element.
+    ///
+    /// The first item kept is the next item of the iterator, and the `step - 1` items following
+    /// each kept item are pulled from the iterator and dropped. No items are skipped after the last
+    /// kept item, so the next expansion continues right after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    ///
+    ///```
+    ///# #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..20);
+    ///
+    /// assert!(tape.expand_step_by(3, 4));
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &4, &8]);
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&9));
+    ///# }
+    /// ```
+    fn expand_step_by(&mut self, n: usize, step: usize) -> bool;
+
     /// Expands the `Ribbon` by consuming items from the iterator while some condition holds and
     /// appending them to the end. Returns `true` if `Ribbon` is expanded by at least one element.
     ///
     /// # Example
     ///
     ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
@@ -107,210 +282,1466 @@ pub trait Ribbon<T> {
     ///
     /// // no more elements smaller than 6, expansion fails
     /// assert_eq!(tape.expand_while(|item| *item < 6), false);
+    ///# }
     /// ```
     fn expand_while<F>(&mut self, f: F) -> bool
     where
-        F: Fn(&T) -> bool;
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        self.expand_while_indexed(|_, item| f(item))
+    }
 
-    /// Removes the item stored at the head of `Ribbon` and returns it (if available).
+    /// Expands the `Ribbon` like [`expand_while`], and additionally returns a reference to the next
+    /// item of the iterator, which is the first item that was not appended. Unless the `Ribbon`
+    /// refused to expand any further, this is the item for which the condition does not hold.
+    /// The item is not consumed, and is appended by the next expansion.
     ///
     /// # Example
     ///
-    /// ```rust
+    ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
-    /// let mut tape = Tape::new(0..10);
+    /// let mut tape = Tape::new("abc;d".chars());
     ///
-    /// tape.expand_n(2);
-    /// assert_eq!(tape.len(), 2);
-    /// assert_eq!(tape.pop_front(), Some(0));
-    /// assert_eq!(tape.pop_front(), Some(1));
-    /// assert_eq!(tape.pop_front(), None);
+    /// let (expanded, next) = tape.expand_while_peek(|c| c.is_alphabetic());
+    /// assert!(expanded);
+    /// assert_eq!(next, Some(&';'));
+    /// assert_eq!(tape.len(), 3);
+    ///# }
     /// ```
-    fn pop_front(&mut self) -> Option<T>;
+    ///
+    /// [`expand_while`]: Ribbon::expand_while
+    fn expand_while_peek<F>(&mut self, f: F) -> (bool, Option<&T>)
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool;
 
-    /// Returns a reference to the item stored at the head of `Ribbon` if item exists. Returns
-    /// `None` otherwise.
+    /// Expands the `Ribbon` by consuming items from the iterator while some condition holds and
+    /// appending them to the end. Returns `true` if `Ribbon` is expanded by at least one element.
+    ///
+    /// The condition receives the number of items appended so far in this call, together with the
+    /// next item.
     ///
     /// # Example
     ///
-    /// ```rust
+    ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
     ///
-    /// tape.expand_n(2);
-    /// assert_eq!(tape.len(), 2);
-    /// assert_eq!(tape.peek_front(), Some(&0));
-    /// assert_eq!(tape.len(), 2);
+    /// // expand by at most 4 items, while items are smaller than 6
+    /// assert!(tape.expand_while_indexed(|i, item| i < 4 && *item < 6));
+    /// assert_eq!(tape.len(), 4);
+    /// assert_eq!(tape.peek_back(), Some(&3));
+    ///
+    /// assert!(tape.expand_while_indexed(|i, item| i < 4 && *item < 6));
+    /// assert_eq!(tape.len(), 6);
+    /// assert_eq!(tape.peek_back(), Some(&5));
+    ///# }
     /// ```
-    fn peek_front(&self) -> Option<&T> {
-        self.peek_at(0)
-    }
+    fn expand_while_indexed<F>(&mut self, f: F) -> bool
+    where
+        Self: Sized,
+        F: Fn(usize, &T) -> bool;
 
-    /// Returns a mutable reference to the item stored at the head of `Ribbon` if item exists.
-    /// Returns `None` otherwise.
+    /// Expands the `Ribbon` by consuming items from the iterator and appending them to the end,
+    /// until an item for which the condition holds is appended. That item is appended as well.
+    /// Returns `true` if `Ribbon` is expanded by at least one element.
     ///
     /// # Example
     ///
-    /// ```rust
+    ///```
+    ///# #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
-    /// let mut tape = Tape::new(0..10);
+    /// let mut tape = Tape::new("let x;".chars());
     ///
-    /// tape.expand_n(2);
-    /// assert_eq!(tape.len(), 2);
-    /// assert_eq!(tape.peek_front(), Some(&0));
-    /// assert_eq!(tape.len(), 2);
+    /// assert!(tape.expand_until(|c| *c == ' '));
+    /// assert_eq!(tape.len(), 4);
+    /// assert_eq!(tape.peek_back(), Some(&' '));
+    ///
+    /// // stops at the end of the iterator if condition never holds
+    /// assert!(tape.expand_until(|c| *c == '='));
+    /// assert_eq!(tape.len(), 6);
+    ///
+    /// // no more elements, expansion fails
+    /// assert_eq!(tape.expand_until(|c| *c == ';'), false);
+    ///# }
     /// ```
-    fn peek_front_mut(&mut self) -> Option<&mut T>;
+    fn expand_until<F>(&mut self, f: F) -> bool
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        let mut expanded = false;
 
-    /// Removes the item stored at the tail of `Ribbon` and returns it if it exists. Returns `None`
-    /// otherwise.
+        while self.expand() {
+            expanded = true;
+
+            if self.peek_back().is_some_and(&f) {
+                break;
+            }
+        }
+
+        expanded
+    }
+
+    /// Returns `true` if the underlying iterator does not produce any more items, so that the
+    /// `Ribbon` cannot be expanded anymore. Items currently found on the `Ribbon` are not taken into
+    /// account.
+    ///
+    /// To find out, the next item may be pulled from the iterator and held in an internal buffer.
+    /// It is not lost, and is appended to the `Ribbon` by the next expansion.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
-    /// let mut tape = Tape::new(0..10);
+    /// let mut tape = Tape::new(0..3);
     ///
-    /// tape.expand_n(3);
+    /// tape.expand_n(2);
+    /// assert!(!tape.source_exhausted());
+    ///
+    /// tape.expand();
+    /// assert!(tape.source_exhausted());
     /// assert_eq!(tape.len(), 3);
-    /// assert_eq!(tape.pop_back(), Some(2));
-    /// assert_eq!(tape.pop_back(), Some(1));
-    /// assert_eq!(tape.pop_back(), Some(0));
-    /// assert_eq!(tape.pop_back(), None);
+    /// # }
     /// ```
-    fn pop_back(&mut self) -> Option<T>;
+    fn source_exhausted(&mut self) -> bool;
 
-    /// Returns a reference to the item stored at the tail of `Ribbon` if item exists. Returns
-    /// `None` otherwise.
+    /// Stops pulling items from the underlying iterator, so that the `Ribbon` behaves as if the
+    /// iterator was exhausted. Expanding or progressing the `Ribbon` afterwards is a no-op, while the
+    /// items currently found on the `Ribbon` can still be peeked and popped.
+    ///
+    /// An item that was pulled from the iterator but not yet appended, e.g. the one rejected by the
+    /// last call to [`expand_while`], is dropped.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
     ///
-    /// tape.expand_n(3);
-    /// assert_eq!(tape.len(), 3);
-    /// assert_eq!(tape.peek_back(), Some(&2));
-    ///
-    /// tape.expand();
-    /// assert_eq!(tape.peek_back(), Some(&3));
+    /// tape.expand_n(2);
+    /// tape.seal();
     ///
-    /// tape.expand();
-    /// assert_eq!(tape.peek_back(), Some(&4));
+    /// assert!(!tape.expand());
+    /// assert!(tape.source_exhausted());
+    /// assert_eq!(tape.pop_front(), Some(0));
+    /// assert_eq!(tape.pop_front(), Some(1));
+    /// assert_eq!(tape.pop_front(), None);
+    /// # }
     /// ```
-    fn peek_back(&self) -> Option<&T> {
-        self.peek_at(self.len() - 1)
-    }
+    ///
+    /// [`expand_while`]: Ribbon::expand_while
+    fn seal(&mut self);
 
-    /// Returns a mutable reference to the item stored at the tail of `Ribbon` if item exists.
-    /// Returns `None` otherwise.
+    /// Limits the number of items the `Ribbon` takes from the iterator from now on to `n` in
+    /// total, across all following calls that expand or progress the `Ribbon`. Once the budget is
+    /// spent, the iterator is treated as exhausted. Calling `expand_take` again replaces the
+    /// remaining budget.
+    ///
+    /// Unlike [`Tape::take`] and [`Band::take`], the type of the `Ribbon` does not change, so the
+    /// budget can also be set through a `&mut` reference or a trait object.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use ribbon::{Ribbon, Tape};
-    ///
-    /// let mut tape = Tape::new(0..10);
-    ///
-    /// tape.expand_n(3);
-    /// assert_eq!(tape.len(), 3);
-    /// assert_eq!(tape.peek_back(), Some(&2));
+    /// use ribbon::{Band, Ribbon};
     ///
-    /// if let Some(item) = tape.peek_back_mut() { *item = 42; }
-    /// assert_eq!(tape.peek_back(), Some(&42));
+    /// let mut band: Band<4, _> = Band::new(0..10);
+    /// band.expand_take(3);
     ///
-    /// tape.expand();
-    /// assert_eq!(tape.peek_back(), Some(&3));
+    /// assert!(band.expand_n(2));
+    /// assert!(band.expand_n(2));
+    /// assert_eq!(band.len(), 3);
     ///
-    /// tape.expand();
-    /// assert_eq!(tape.peek_back(), Some(&4));
+    /// assert!(!band.expand());
+    /// assert!(band.source_exhausted());
     /// ```
-    fn peek_back_mut(&mut self) -> Option<&mut T>;
+    ///
+    /// [`Tape::take`]: crate::Tape::take
+    /// [`Band::take`]: crate::Band::take
+    fn expand_take(&mut self, n: usize);
 
-    /// Returns a reference to the item stored at the given index of `Ribbon` if item exists.
-    /// Returns `None` if index out of bounds.
+    /// Removes the item stored at the head of `Ribbon` and returns it (if available).
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
     ///
-    /// tape.expand_n(5);
-    /// assert_eq!(tape.len(), 5);
-    /// assert_eq!(tape.peek_at(0), Some(&0));
-    /// assert_eq!(tape.peek_at(2), Some(&2));
-    /// assert_eq!(tape.peek_at(3), Some(&3));
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.pop_front(), Some(0));
+    /// assert_eq!(tape.pop_front(), Some(1));
+    /// assert_eq!(tape.pop_front(), None);
+    /// # }
     /// ```
-    fn peek_at(&self, index: usize) -> Option<&T>;
+    fn pop_front(&mut self) -> Option<T>;
 
-    /// Returns a mutable reference to the item stored at the given index of `Ribbon` if item
-    /// exists. Returns None otherwise, or if index out of bounds.
+    /// Removes the item stored at the head of `Ribbon` and returns it, if the given condition holds
+    /// for it. Otherwise, the `Ribbon` is left unchanged and `None` is returned. The `Ribbon` is
+    /// not expanded.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
-    /// let mut tape = Tape::new(0..10);
-    ///
-    /// tape.expand_n(5);
-    /// assert_eq!(tape.len(), 5);
-    /// assert_eq!(tape.peek_at(0), Some(&0));
-    ///
-    /// if let Some(item) = tape.peek_at_mut(0) { *item = 42; }
+    /// let mut tape = Tape::new("+1".chars());
     ///
-    /// assert_eq!(tape.peek_at(0), Some(&42));
-    /// assert_eq!(tape.peek_at(3), Some(&3));
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.pop_front_if(|c| *c == '-'), None);
+    /// assert_eq!(tape.pop_front_if(|c| *c == '+'), Some('+'));
+    /// assert_eq!(tape.peek_front(), Some(&'1'));
+    /// # }
     /// ```
-    fn peek_at_mut(&mut self, index: usize) -> Option<&mut T>;
+    fn pop_front_if<F>(&mut self, f: F) -> Option<T>
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        if self.peek_front().is_some_and(f) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
 
-    /// Returns the number of items currently found on the `Ribbon`.
+    /// Removes up to `n` items from the head of `Ribbon` and returns an iterator over them, in
+    /// order. Yields fewer items if the `Ribbon` holds less than `n` items. The `Ribbon` is not
+    /// expanded.
+    ///
+    /// Items are removed lazily, as the returned iterator is consumed.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
     ///
     /// tape.expand_n(5);
-    /// assert_eq!(tape.len(), 5);
+    /// let items: Vec<_> = tape.drain_front(3).collect();
     ///
-    /// tape.expand_n(2);
-    /// assert_eq!(tape.len(), 7);
+    /// assert_eq!(items, vec![0, 1, 2]);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.peek_front(), Some(&3));
+    /// # }
     /// ```
-    fn len(&self) -> usize;
+    fn drain_front(&mut self, n: usize) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+    {
+        (0..n).map_while(move |_| self.pop_front())
+    }
 
-    /// Returns `true` if `Ribbon` does not contain any items at the moment.
+    /// Removes the items within the given range of indices from `Ribbon` and returns an iterator
+    /// over them, in order. Items after the range are shifted towards the head. The `Ribbon` is
+    /// not expanded.
+    ///
+    /// The whole range is removed even if the returned iterator is dropped before it is fully
+    /// consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end of the range is
+    /// greater than the length of `Ribbon`.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # #[cfg(feature = "alloc")] {
     /// use ribbon::{Ribbon, Tape};
     ///
     /// let mut tape = Tape::new(0..10);
-    /// assert_eq!(tape.len(), 0);
-    ///
-    /// tape.expand();
-    /// assert_eq!(tape.len(), 1);
     ///
     /// tape.expand_n(5);
-    /// assert_eq!(tape.len(), 6);
+    /// let items: Vec<_> = tape.drain(1..3).collect();
+    ///
+    /// assert_eq!(items, vec![1, 2]);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &3, &4]);
+    /// # }
     /// ```
-    fn is_empty(&self) -> bool {
-        self.len() == 0
+    fn drain<R>(&mut self, range: R) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("attempted to drain from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .expect("attempted to drain up to maximum usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end, "drain starts at {start} but ends at {end}");
+        assert!(
+            end <= len,
+            "range end index {end} out of range for ribbon of length {len}"
+        );
+
+        Drain::new(self, start, end - start)
     }
-}
 
-/// Extension trait on types that implement [`Iterator`] trait with convenient functions to convert
+    /// Creates an iterator that advances this and the other `Ribbon` in lockstep, yielding pairs
+    /// of their items. Items currently found on the `Ribbon`s are yielded first, and both of them
+    /// are expanded as needed. Stops as soon as either `Ribbon` is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let pairs: Vec<_> = Tape::new(0..3).zip_ribbon(Tape::new("abcd".chars())).collect();
+    /// assert_eq!(pairs, vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// # }
+    /// ```
+    fn zip_ribbon<U, R>(self, other: R) -> ZipRibbon<Self, R, T, U>
+    where
+        Self: Sized,
+        R: Ribbon<U>,
+    {
+        ZipRibbon::new(self, other)
+    }
+
+    /// Consumes the `Ribbon` and collects the items currently found on it, from head to tail,
+    /// followed by all the remaining items of the underlying iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// tape.pop_front();
+    ///
+    /// let items: Vec<_> = tape.collect_remaining();
+    /// assert_eq!(items, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// # }
+    /// ```
+    fn collect_remaining<C>(mut self) -> C
+    where
+        Self: Sized,
+        C: FromIterator<T>,
+    {
+        core::iter::from_fn(move || {
+            if self.is_empty() {
+                self.expand();
+            }
+
+            self.pop_front()
+        })
+        .collect()
+    }
+
+    /// Returns a reference to the item stored at the head of `Ribbon` if item exists. Returns
+    /// `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.peek_front(), Some(&0));
+    /// assert_eq!(tape.len(), 2);
+    /// # }
+    /// ```
+    fn peek_front(&self) -> Option<&T> {
+        self.peek_at(0)
+    }
+
+    /// Returns a clone of the item stored at the head of `Ribbon` if item exists. Returns `None`
+    /// otherwise. The item stays on the `Ribbon`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// match tape.front_cloned() {
+    ///     Some(0) => tape.expand(),
+    ///     _ => false,
+    /// };
+    ///
+    /// assert_eq!(tape.len(), 4);
+    /// # }
+    /// ```
+    fn front_cloned(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.peek_front().cloned()
+    }
+
+    /// Returns a mutable reference to the item stored at the head of `Ribbon` if item exists.
+    /// Returns `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.peek_front(), Some(&0));
+    /// assert_eq!(tape.len(), 2);
+    /// # }
+    /// ```
+    fn peek_front_mut(&mut self) -> Option<&mut T>;
+
+    /// Returns an iterator over references to the first `n` items currently found on the `Ribbon`,
+    /// from head to tail. Yields fewer items if the `Ribbon` holds less than `n` items. The
+    /// `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// let items: Vec<_> = tape.peek_front_n(3).collect();
+    /// assert_eq!(items, vec![&0, &1, &2]);
+    ///
+    /// let items: Vec<_> = tape.peek_front_n(7).collect();
+    /// assert_eq!(items.len(), 5);
+    /// # }
+    /// ```
+    fn peek_front_n<'a>(&'a self, n: usize) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        self.iter().take(n)
+    }
+
+    /// Removes the item stored at the tail of `Ribbon` and returns it if it exists. Returns `None`
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.len(), 3);
+    /// assert_eq!(tape.pop_back(), Some(2));
+    /// assert_eq!(tape.pop_back(), Some(1));
+    /// assert_eq!(tape.pop_back(), Some(0));
+    /// assert_eq!(tape.pop_back(), None);
+    /// # }
+    /// ```
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns a reference to the item stored at the tail of `Ribbon` if item exists. Returns
+    /// `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.len(), 3);
+    /// assert_eq!(tape.peek_back(), Some(&2));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&3));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&4));
+    /// # }
+    /// ```
+    fn peek_back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.peek_at(self.len() - 1)
+    }
+
+    /// Returns a clone of the item stored at the tail of `Ribbon` if item exists. Returns `None`
+    /// otherwise. The item stays on the `Ribbon`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.back_cloned(), Some(2));
+    /// assert_eq!(tape.len(), 3);
+    /// # }
+    /// ```
+    fn back_cloned(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.peek_back().cloned()
+    }
+
+    /// Returns a mutable reference to the item stored at the tail of `Ribbon` if item exists.
+    /// Returns `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.len(), 3);
+    /// assert_eq!(tape.peek_back(), Some(&2));
+    ///
+    /// if let Some(item) = tape.peek_back_mut() { *item = 42; }
+    /// assert_eq!(tape.peek_back(), Some(&42));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&3));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&4));
+    /// # }
+    /// ```
+    fn peek_back_mut(&mut self) -> Option<&mut T>;
+
+    /// Returns an iterator over references to the last `n` items currently found on the `Ribbon`,
+    /// from head to tail. Yields fewer items if the `Ribbon` holds less than `n` items. The
+    /// `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// let items: Vec<_> = tape.peek_back_n(3).collect();
+    /// assert_eq!(items, vec![&2, &3, &4]);
+    ///
+    /// let items: Vec<_> = tape.peek_back_n(7).collect();
+    /// assert_eq!(items.len(), 5);
+    /// # }
+    /// ```
+    fn peek_back_n<'a>(&'a self, n: usize) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        self.iter().skip(self.len().saturating_sub(n))
+    }
+
+    /// Returns a reference to the item stored `index` positions before the tail of `Ribbon` if item
+    /// exists, so that index `0` refers to the tail. Returns `None` if index out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.peek_back_at(0), Some(&4));
+    /// assert_eq!(tape.peek_back_at(3), Some(&1));
+    /// assert_eq!(tape.peek_back_at(5), None);
+    /// # }
+    /// ```
+    fn peek_back_at(&self, index: usize) -> Option<&T> {
+        let index = self.len().checked_sub(index)?.checked_sub(1)?;
+        self.peek_at(index)
+    }
+
+    /// Returns references to the items stored at the head and at the tail of `Ribbon`, like
+    /// [`peek_front`] and [`peek_back`] would. If `Ribbon` holds a single item, both references
+    /// point to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// assert_eq!(tape.ends(), (None, None));
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.ends(), (Some(&0), Some(&2)));
+    /// # }
+    /// ```
+    ///
+    /// [`peek_front`]: Ribbon::peek_front
+    /// [`peek_back`]: Ribbon::peek_back
+    fn ends(&self) -> (Option<&T>, Option<&T>) {
+        (self.peek_front(), self.peek_back())
+    }
+
+    /// Returns mutable references to the items stored at the head and at the tail of `Ribbon`.
+    ///
+    /// The references must not alias, so if `Ribbon` holds a single item, only the reference to
+    /// the head is returned, and the second one is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.ends_mut(), (Some(&mut 0), None));
+    ///
+    /// tape.expand_n(2);
+    /// if let (Some(front), Some(back)) = tape.ends_mut() {
+    ///     core::mem::swap(front, back);
+    /// }
+    /// assert_eq!(tape.ends(), (Some(&2), Some(&0)));
+    /// # }
+    /// ```
+    fn ends_mut(&mut self) -> (Option<&mut T>, Option<&mut T>)
+    where
+        Self: Sized,
+    {
+        let mut iter = self.iter_mut();
+        (iter.next(), iter.last())
+    }
+
+    /// Returns an iterator over references to the items currently found on the `Ribbon` within the
+    /// given range of indices, from head to tail. The range is clamped to the number of items the
+    /// `Ribbon` holds, so it never panics. The `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// let items: Vec<_> = tape.peek_range(1..3).collect();
+    /// assert_eq!(items, vec![&1, &2]);
+    ///
+    /// let items: Vec<_> = tape.peek_range(3..).collect();
+    /// assert_eq!(items, vec![&3, &4]);
+    ///
+    /// let items: Vec<_> = tape.peek_range(2..100).collect();
+    /// assert_eq!(items.len(), 3);
+    /// # }
+    /// ```
+    fn peek_range<'a, R>(&'a self, range: R) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a,
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+
+        self.iter().skip(start).take(end.saturating_sub(start))
+    }
+
+    /// Returns a reference to the item stored at the given index of `Ribbon` if item exists.
+    /// Returns `None` if index out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.len(), 5);
+    /// assert_eq!(tape.peek_at(0), Some(&0));
+    /// assert_eq!(tape.peek_at(2), Some(&2));
+    /// assert_eq!(tape.peek_at(3), Some(&3));
+    /// # }
+    /// ```
+    fn peek_at(&self, index: usize) -> Option<&T>;
+
+    /// Returns a reference to the item stored at the given index of `Ribbon` if item exists.
+    /// Returns the given default otherwise, e.g. a sentinel marking the end of input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// const EOF: char = '\0';
+    ///
+    /// let mut tape = Tape::new("ab".chars());
+    ///
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.peek_at_or(1, &EOF), &'b');
+    /// assert_eq!(tape.peek_at_or(2, &EOF), &EOF);
+    /// # }
+    /// ```
+    fn peek_at_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.peek_at(index).unwrap_or(default)
+    }
+
+    /// Returns a mutable reference to the item stored at the given index of `Ribbon` if item
+    /// exists. Returns None otherwise, or if index out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.len(), 5);
+    /// assert_eq!(tape.peek_at(0), Some(&0));
+    ///
+    /// if let Some(item) = tape.peek_at_mut(0) { *item = 42; }
+    ///
+    /// assert_eq!(tape.peek_at(0), Some(&42));
+    /// assert_eq!(tape.peek_at(3), Some(&3));
+    /// # }
+    /// ```
+    fn peek_at_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    /// Returns mutable references to the items stored at the two given indices of `Ribbon`, in the
+    /// order of the indices. Returns `None` if the indices are equal, or if either of them is out of
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// if let Some((a, b)) = tape.get_disjoint_mut(1, 3) {
+    ///     core::mem::swap(a, b);
+    /// }
+    ///
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &3, &2, &1, &4]);
+    /// assert_eq!(tape.get_disjoint_mut(2, 2), None);
+    /// # }
+    /// ```
+    fn get_disjoint_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)>;
+
+    /// Replaces the item stored at the given index of `Ribbon` with the given value, and returns
+    /// the previous item. Returns `None` if index out of bounds, in which case the value is
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.replace_at(1, 42), Some(1));
+    /// assert_eq!(tape.replace_at(3, 42), None);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &42, &2]);
+    /// # }
+    /// ```
+    fn replace_at(&mut self, index: usize, value: T) -> Option<T> {
+        let item = self.peek_at_mut(index)?;
+        Some(core::mem::replace(item, value))
+    }
+
+    /// Returns an iterator over references to the items currently found on the `Ribbon`, from head
+    /// to tail. Items are not consumed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// let items: Vec<_> = tape.iter().collect();
+    ///
+    /// assert_eq!(items, vec![&0, &1, &2]);
+    /// assert_eq!(tape.len(), 3);
+    /// # }
+    /// ```
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a;
+
+    /// Returns an iterator over mutable references to the items currently found on the `Ribbon`,
+    /// from head to tail. Items are not consumed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// tape.iter_mut().for_each(|item| *item *= 10);
+    ///
+    /// assert_eq!(tape.peek_at(0), Some(&0));
+    /// assert_eq!(tape.peek_at(1), Some(&10));
+    /// assert_eq!(tape.peek_at(2), Some(&20));
+    /// # }
+    /// ```
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        Self: Sized,
+        T: 'a;
+
+    /// Swaps the items at the given indices of the `Ribbon`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// tape.swap(0, 2);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+    /// # }
+    /// ```
+    fn swap(&mut self, i: usize, j: usize);
+
+    /// Inserts the value at the given index of `Ribbon`, shifting all items after it towards the
+    /// tail. The value does not come from the underlying iterator, which is left untouched.
+    ///
+    /// A `Ribbon` that cannot grow any further may have to give up an item in order to make room,
+    /// which is then returned. Returns `None` if nothing was given up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is greater than the length of `Ribbon`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.insert_at(1, 42), None);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &42, &1, &2]);
+    /// # }
+    /// ```
+    fn insert_at(&mut self, index: usize, value: T) -> Option<T>;
+
+    /// Removes and returns the item at the given index of `Ribbon`, shifting all items after it
+    /// towards the head. Returns `None` if index out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(4);
+    /// assert_eq!(tape.remove_at(1), Some(1));
+    /// assert_eq!(tape.remove_at(3), None);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &2, &3]);
+    /// # }
+    /// ```
+    fn remove_at(&mut self, index: usize) -> Option<T>;
+
+    /// Removes and returns the item at the given index of `Ribbon`, replacing it with the tail of
+    /// `Ribbon`. Returns `None` if index out of bounds.
+    ///
+    /// This does not preserve the order of the items, but runs in `O(1)`. Use [`remove_at`] to
+    /// keep the order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(4);
+    /// assert_eq!(tape.swap_remove(1), Some(1));
+    /// assert_eq!(tape.swap_remove(3), None);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &3, &2]);
+    /// # }
+    /// ```
+    ///
+    /// [`remove_at`]: Ribbon::remove_at
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+
+        if index > last {
+            return None;
+        }
+
+        self.swap(index, last);
+        self.pop_back()
+    }
+
+    /// Rotates the items currently found on the `Ribbon` `n` places to the left, so that the item
+    /// at index `n` becomes the head, and the first `n` items are moved to the tail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than [`len`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// tape.rotate_left(2);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &0, &1]);
+    /// # }
+    /// ```
+    ///
+    /// [`len`]: Ribbon::len
+    fn rotate_left(&mut self, n: usize);
+
+    /// Rotates the items currently found on the `Ribbon` `n` places to the right, so that the last
+    /// `n` items are moved to the head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than [`len`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// tape.rotate_right(2);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&3, &4, &0, &1, &2]);
+    /// # }
+    /// ```
+    ///
+    /// [`len`]: Ribbon::len
+    fn rotate_right(&mut self, n: usize);
+
+    /// Removes items from the tail of the `Ribbon` until it holds at most `len` items. Has no
+    /// effect if the `Ribbon` holds less items. The underlying iterator is left intact.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// tape.truncate(2);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.peek_back(), Some(&1));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&5));
+    /// # }
+    /// ```
+    fn truncate(&mut self, len: usize);
+
+    /// Retains only the items currently found on the `Ribbon` for which the given condition holds,
+    /// keeping their order. Other items are removed and dropped. The underlying iterator is left
+    /// intact.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// tape.retain(|item| item % 2 == 0);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &2, &4]);
+    /// # }
+    /// ```
+    fn retain<F>(&mut self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(&T) -> bool;
+
+    /// Removes consecutive repeated items currently found on the `Ribbon`, keeping the first item
+    /// of each run. Only the items currently found on the `Ribbon` are affected, items expanded
+    /// later are not deduplicated, not even against the current tail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new([1, 1, 2, 2, 2, 3, 1].into_iter());
+    ///
+    /// tape.expand_n(7);
+    /// tape.dedup_buffered();
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &1]);
+    /// # }
+    /// ```
+    fn dedup_buffered(&mut self)
+    where
+        T: PartialEq;
+
+    /// Returns `true` if the given item is currently found on the `Ribbon`. The `Ribbon` is not
+    /// expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("(a)".chars());
+    ///
+    /// tape.expand_n(2);
+    /// assert!(tape.contains(&'a'));
+    /// assert!(!tape.contains(&')'));
+    /// # }
+    /// ```
+    fn contains(&self, item: &T) -> bool
+    where
+        Self: Sized,
+        T: PartialEq,
+    {
+        self.iter().any(|i| i == item)
+    }
+
+    /// Returns the index of the first item currently found on the `Ribbon` that satisfies the
+    /// given condition, or `None` if there is no such item. The `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("(a)".chars());
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.position(|c| *c == ')'), Some(2));
+    /// assert_eq!(tape.position(|c| *c == 'b'), None);
+    /// # }
+    /// ```
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        self.iter().position(f)
+    }
+
+    /// Returns the index of the last item currently found on the `Ribbon` that satisfies the given
+    /// condition, or `None` if there is no such item. Items are checked from tail to head, but the
+    /// index is still counted from the head. The `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("(a)(b)".chars());
+    ///
+    /// tape.expand_n(6);
+    /// assert_eq!(tape.position(|c| *c == '('), Some(0));
+    /// assert_eq!(tape.rposition(|c| *c == '('), Some(3));
+    /// assert_eq!(tape.rposition(|c| *c == 'c'), None);
+    /// # }
+    /// ```
+    fn rposition<F>(&self, f: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        (0..self.len())
+            .rev()
+            .find(|&index| self.peek_at(index).is_some_and(&f))
+    }
+
+    /// Folds the items currently found on the `Ribbon` into an accumulator, from head to tail.
+    /// Items are not consumed, and the `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(["let", "x", "="].into_iter());
+    ///
+    /// tape.expand_n(3);
+    /// assert_eq!(tape.fold_buffered(0, |len, token| len + token.len()), 5);
+    /// assert_eq!(tape.len(), 3);
+    /// # }
+    /// ```
+    fn fold_buffered<B, F>(&self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Sums the items currently found on the `Ribbon`. Items are not consumed, and the `Ribbon` is
+    /// not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(1..10);
+    ///
+    /// tape.expand_n(4);
+    /// assert_eq!(tape.sum_buffered::<i32>(), 10);
+    /// assert_eq!(tape.len(), 4);
+    /// # }
+    /// ```
+    fn sum_buffered<'a, S>(&'a self) -> S
+    where
+        Self: Sized,
+        T: 'a,
+        S: Sum<&'a T>,
+    {
+        self.iter().sum()
+    }
+
+    /// Returns a reference to the smallest item currently found on the `Ribbon`. If several items
+    /// are equally small, the one closest to the head is returned. Returns `None` if the `Ribbon` is
+    /// empty. Items are not consumed, and the `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new([3, 1, 4, 1, 5].into_iter());
+    ///
+    /// tape.expand_n(4);
+    /// assert_eq!(tape.min_buffered(), Some(&1));
+    /// assert_eq!(tape.len(), 4);
+    /// # }
+    /// ```
+    fn min_buffered(&self) -> Option<&T>
+    where
+        Self: Sized,
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the largest item currently found on the `Ribbon`. If several items
+    /// are equally large, the one closest to the head is returned. Returns `None` if the `Ribbon` is
+    /// empty. Items are not consumed, and the `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new([3, 1, 4, 1, 5].into_iter());
+    ///
+    /// tape.expand_n(4);
+    /// assert_eq!(tape.max_buffered(), Some(&4));
+    /// assert_eq!(tape.len(), 4);
+    /// # }
+    /// ```
+    fn max_buffered(&self) -> Option<&T>
+    where
+        Self: Sized,
+        T: Ord,
+    {
+        // `Iterator::max` returns the last of equal items, so the head-most one is kept by hand
+        self.iter()
+            .reduce(|max, item| if item > max { item } else { max })
+    }
+
+    /// Returns the number of items currently found on the `Ribbon` that satisfy the predicate.
+    /// Items are not consumed, and the `Ribbon` is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("(a(b)".chars());
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.count_buffered(|c| *c == '('), 2);
+    /// assert_eq!(tape.len(), 5);
+    /// # }
+    /// ```
+    fn count_buffered<F>(&self, f: F) -> usize
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        self.iter().filter(|item| f(item)).count()
+    }
+
+    /// Returns `true` if the items currently found on this and the other `Ribbon` are equal, from
+    /// head to tail. The `Ribbon`s are compared regardless of how they store their items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Band, Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// let mut band: Band<3, _> = Band::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// tape.drain_front(2).for_each(drop);
+    /// band.expand_n(5);
+    ///
+    /// assert!(tape.content_eq(&band));
+    /// # }
+    /// ```
+    fn content_eq<R>(&self, other: &R) -> bool
+    where
+        Self: Sized,
+        R: Ribbon<T> + ?Sized,
+        T: PartialEq,
+    {
+        self.len() == other.len()
+            && self
+                .iter()
+                .enumerate()
+                .all(|(i, item)| other.peek_at(i) == Some(item))
+    }
+
+    /// Removes all items currently found on the `Ribbon`. The underlying iterator is left intact,
+    /// so further expansion continues with the next item it produces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(3);
+    /// tape.clear();
+    /// assert!(tape.is_empty());
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_front(), Some(&3));
+    /// # }
+    /// ```
+    fn clear(&mut self);
+
+    /// Returns the number of items currently found on the `Ribbon`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.len(), 5);
+    ///
+    /// tape.expand_n(2);
+    /// assert_eq!(tape.len(), 7);
+    /// # }
+    /// ```
+    fn len(&self) -> usize;
+
+    /// Returns the number of items the `Ribbon` can hold at the moment. Holding more items requires
+    /// a dynamically sized `Ribbon` to allocate, and a fix-sized `Ribbon` to drop items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Band, Ribbon, Tape};
+    ///
+    /// let tape = Tape::with_capacity(0..10, 8);
+    /// assert!(tape.capacity() >= 8);
+    ///
+    /// let band: Band<4, _> = Band::new(0..10);
+    /// assert_eq!(band.capacity(), 4);
+    /// # }
+    /// ```
+    fn capacity(&self) -> usize;
+
+    /// Returns `true` if `Ribbon` cannot be expanded without dropping or rejecting an item. What
+    /// happens on expansion then depends on the `Ribbon`, e.g. a [`Band`] drops its head by
+    /// default, while [`progress`] keeps the number of items the same either way.
+    ///
+    /// A `Ribbon` without a bound on the number of items it holds is never full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Band, Ribbon, Tape};
+    ///
+    /// let mut band: Band<2, _> = Band::new(0..10);
+    /// band.expand_n(2);
+    /// assert!(band.is_full());
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(10);
+    /// assert!(!tape.is_full());
+    /// # }
+    /// ```
+    ///
+    /// [`Band`]: crate::Band
+    /// [`progress`]: Ribbon::progress
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if `Ribbon` does not contain any items at the moment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// assert_eq!(tape.len(), 0);
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.len(), 1);
+    ///
+    /// tape.expand_n(5);
+    /// assert_eq!(tape.len(), 6);
+    /// # }
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A boxed [`Ribbon`] trait object, that can hold any kind of `Ribbon` over items of type `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use ribbon::{Band, DynRibbon, Ribbon, Tape};
+///
+/// let mut ribbons: Vec<DynRibbon<u32>> = vec![
+///     Box::new(Tape::new(0..10)),
+///     Box::new(Band::<2, _>::new(0..10)),
+/// ];
+///
+/// for ribbon in &mut ribbons {
+///     ribbon.expand_n(3);
+/// }
+///
+/// assert_eq!(ribbons[0].len(), 3);
+/// assert_eq!(ribbons[1].len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+pub type DynRibbon<'a, T> = alloc::boxed::Box<dyn Ribbon<T> + 'a>;
+
+/// Extension trait on types that implement [`Iterator`] trait with convenient functions to convert
 /// the given [`Iterator`] into a [`Band`] or [`Tape`].
 ///
 /// [`Band`]: crate::Band
@@ -318,7 +1749,28 @@ pub trait Ribbon<T> {
 pub trait Enroll {
     /// Creates a new [`Band`] from the given Iterator.
     ///
+    /// The capacity `N` can be given with a turbofish, or inferred from the annotated type, e.g.
+    /// one of the presets [`Band8`], [`Band16`] and [`Band32`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Band16, Enroll, Ribbon};
+    ///
+    /// let band = (0..10).band::<4>();
+    /// assert_eq!(band.capacity(), 4);
+    ///
+    /// let band: Band<4, _> = (0..10).band();
+    /// assert_eq!(band.capacity(), 4);
+    ///
+    /// let band: Band16<_> = (0..10).band();
+    /// assert_eq!(band.capacity(), 16);
+    /// ```
+    ///
     /// [`Band`]: crate::Band
+    /// [`Band8`]: crate::Band8
+    /// [`Band16`]: crate::Band16
+    /// [`Band32`]: crate::Band32
     fn band<const N: usize>(self) -> crate::Band<N, Self>
     where
         Self: Sized + Iterator;
@@ -326,9 +1778,63 @@ pub trait Enroll {
     /// Creates a new [`Tape`] from the given Iterator.
     ///
     /// [`Tape`]: crate::Tape
+    #[cfg(feature = "alloc")]
     fn tape(self) -> crate::Tape<Self>
     where
         Self: Sized + Iterator;
+
+    /// Creates a new [`DynBand`] from the given Iterator, that holds at most `capacity` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// [`DynBand`]: crate::DynBand
+    #[cfg(feature = "alloc")]
+    fn dyn_band(self, capacity: usize) -> crate::DynBand<Self>
+    where
+        Self: Sized + Iterator;
+
+    /// Creates an iterator over overlapping windows of `K` consecutive items of the given
+    /// Iterator. Items are cloned into each window. No window is produced if the Iterator produces
+    /// less than `K` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::Enroll;
+    ///
+    /// let windows: Vec<_> = (0..5).windows::<3>().collect();
+    /// assert_eq!(windows, vec![[0, 1, 2], [1, 2, 3], [2, 3, 4]]);
+    /// ```
+    fn windows<const K: usize>(self) -> crate::Windows<K, Self>
+    where
+        Self: Sized + Iterator;
+
+    /// Creates an iterator over non-overlapping chunks of `size` consecutive items of the given
+    /// Iterator. The last chunk is shorter if the Iterator does not produce a multiple of `size`
+    /// items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::Enroll;
+    ///
+    /// let chunks: Vec<_> = (0..7).chunks(3).collect();
+    /// assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn chunks(self, size: usize) -> crate::Chunks<Self>
+    where
+        Self: Sized + Iterator;
 }
 
 impl<I> Enroll for I
@@ -342,10 +1848,115 @@ where
         crate::Band::<N, Self>::new(self)
     }
 
+    #[cfg(feature = "alloc")]
     fn tape(self) -> Tape<Self>
     where
         Self: Sized + Iterator,
     {
         crate::Tape::new(self)
     }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_band(self, capacity: usize) -> DynBand<Self>
+    where
+        Self: Sized + Iterator,
+    {
+        crate::DynBand::new(self, capacity)
+    }
+
+    fn windows<const K: usize>(self) -> Windows<K, Self>
+    where
+        Self: Sized + Iterator,
+    {
+        crate::Windows::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized + Iterator,
+    {
+        crate::Chunks::new(self, size)
+    }
+}
+
+/// Extension trait on types that implement [`IntoIterator`] trait with convenient functions to
+/// convert them into a [`Band`] or [`Tape`], without calling [`IntoIterator::into_iter`] first.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use ribbon::{EnrollInto, Ribbon};
+///
+/// let mut tape = vec![1, 2, 3].into_tape();
+/// tape.expand_n(2);
+/// assert_eq!(tape.peek_back(), Some(&2));
+///
+/// let mut band = [1, 2, 3].into_band::<2>();
+/// band.expand_n(3);
+/// assert_eq!(band.peek_front(), Some(&2));
+/// # }
+/// ```
+///
+/// [`Band`]: crate::Band
+/// [`Tape`]: crate::Tape
+pub trait EnrollInto: IntoIterator + Sized {
+    /// Creates a new [`Band`] from the iterator of the given value.
+    ///
+    /// [`Band`]: crate::Band
+    fn into_band<const N: usize>(self) -> crate::Band<N, Self::IntoIter>;
+
+    /// Creates a new [`Tape`] from the iterator of the given value.
+    ///
+    /// [`Tape`]: crate::Tape
+    #[cfg(feature = "alloc")]
+    fn into_tape(self) -> crate::Tape<Self::IntoIter>;
+}
+
+impl<I> EnrollInto for I
+where
+    I: IntoIterator,
+{
+    fn into_band<const N: usize>(self) -> Band<N, Self::IntoIter> {
+        crate::Band::<N, Self::IntoIter>::new(self.into_iter())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn into_tape(self) -> Tape<Self::IntoIter> {
+        crate::Tape::new(self.into_iter())
+    }
+}
+
+/// Returns mutable references to the items at the two given indices of the pair of slices, which
+/// hold the items in order. Returns `None` if the indices are equal, or if either of them is out of
+/// bounds.
+pub(crate) fn get_disjoint_mut<'a, T>(
+    first: &'a mut [T],
+    second: &'a mut [T],
+    i: usize,
+    j: usize,
+) -> Option<(&'a mut T, &'a mut T)> {
+    let split = first.len();
+
+    match (i.checked_sub(split), j.checked_sub(split)) {
+        (None, None) => get_pair_mut(first, i, j),
+        (Some(i), Some(j)) => get_pair_mut(second, i, j),
+        (None, Some(j)) => Some((first.get_mut(i)?, second.get_mut(j)?)),
+        (Some(i), None) => Some((second.get_mut(i)?, first.get_mut(j)?)),
+    }
+}
+
+/// Returns mutable references to the items at the two given indices of the slice. Returns `None`
+/// if the indices are equal, or if either of them is out of bounds.
+pub(crate) fn get_pair_mut<T>(slice: &mut [T], i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+    let (low, high) = (i.min(j), i.max(j));
+    if low == high || high >= slice.len() {
+        return None;
+    }
+
+    let (head, tail) = slice.split_at_mut(high);
+    let (low, high) = (&mut head[low], &mut tail[0]);
+
+    Some(if i < j { (low, high) } else { (high, low) })
 }
@@ -0,0 +1,168 @@
+//! Wrapper around the iterator that feeds a [`Ribbon`].
+//!
+//! [`Ribbon`]: crate::Ribbon
+
+/// Iterator with a single slot for an item that was pulled, but not yet consumed.
+///
+/// Works like [`Peekable`], except that the wrapped iterator can be recovered. Like [`Fuse`], it
+/// never polls the wrapped iterator again once it returned `None`, so iterators that resume after
+/// returning `None` are treated as exhausted.
+///
+/// [`Peekable`]: core::iter::Peekable
+/// [`Fuse`]: core::iter::Fuse
+#[derive(Debug)]
+pub(crate) struct Source<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    peeked: Option<I::Item>,
+    /// Set once the wrapped iterator returned `None`.
+    done: bool,
+    /// Number of items that can still be returned, if limited.
+    budget: Option<usize>,
+}
+
+impl<I> Source<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Source {
+            iter,
+            peeked: None,
+            done: false,
+            budget: None,
+        }
+    }
+
+    /// Returns the next item, taking the peeked one first if available.
+    pub(crate) fn next(&mut self) -> Option<I::Item> {
+        if self.budget == Some(0) {
+            return None;
+        }
+
+        let next = self.peeked.take().or_else(|| self.pull());
+        if next.is_some() {
+            self.budget = self.budget.map(|budget| budget - 1);
+        }
+
+        next
+    }
+
+    /// Limits the number of items returned from now on to `n`, replacing the previous limit.
+    pub(crate) fn limit(&mut self, n: usize) {
+        self.budget = Some(n);
+    }
+
+    /// Stops pulling items from the wrapped iterator, as if it was exhausted. The peeked item, if
+    /// any, is dropped.
+    pub(crate) fn seal(&mut self) {
+        self.peeked = None;
+        self.done = true;
+    }
+
+    /// Pulls the next `n` items and drops them, taking the peeked one first if available.
+    pub(crate) fn discard(&mut self, n: usize) {
+        for _ in 0..n {
+            if self.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Returns a reference to the next item without consuming it.
+    pub(crate) fn peek(&mut self) -> Option<&I::Item> {
+        if self.budget == Some(0) {
+            return None;
+        }
+
+        if self.peeked.is_none() {
+            self.peeked = self.pull();
+        }
+
+        self.peeked.as_ref()
+    }
+
+    /// Pulls the next item from the wrapped iterator, unless it already returned `None`.
+    fn pull(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+
+        let next = self.iter.next();
+        self.done = next.is_none();
+        next
+    }
+
+    /// Transforms the peeked item (if any) and all following items with the given function.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn map<U, F>(self, mut f: F) -> Source<core::iter::Map<I, F>>
+    where
+        F: FnMut(I::Item) -> U,
+    {
+        Source {
+            peeked: self.peeked.map(&mut f),
+            iter: self.iter.map(f),
+            done: self.done,
+            budget: self.budget,
+        }
+    }
+
+    /// Limits the peeked item (if any) and all following items to `n` items in total.
+    pub(crate) fn take(self, n: usize) -> Source<core::iter::Take<I>> {
+        let peeked = self.peeked.filter(|_| n > 0);
+        let remaining = n - usize::from(peeked.is_some());
+
+        Source {
+            peeked,
+            iter: self.iter.take(remaining),
+            done: self.done,
+            budget: self.budget,
+        }
+    }
+
+    /// Returns the bounds on the remaining number of items, see [`Iterator::size_hint`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        let (lower, upper) = if self.done {
+            (peeked, Some(peeked))
+        } else {
+            let (lower, upper) = self.iter.size_hint();
+
+            (
+                lower.saturating_add(peeked),
+                upper.and_then(|upper| upper.checked_add(peeked)),
+            )
+        };
+
+        match self.budget {
+            Some(budget) => (
+                lower.min(budget),
+                Some(upper.map_or(budget, |upper| upper.min(budget))),
+            ),
+            None => (lower, upper),
+        }
+    }
+
+    /// Returns the peeked item (if any) and the wrapped iterator.
+    pub(crate) fn into_parts(self) -> (Option<I::Item>, I) {
+        (self.peeked, self.iter)
+    }
+}
+
+impl<I> Clone for Source<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            peeked: self.peeked.clone(),
+            done: self.done,
+            budget: self.budget,
+        }
+    }
+}
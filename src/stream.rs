@@ -0,0 +1,301 @@
+//! Implementation of dynamically sized data structures over asynchronous [`Stream`]s.
+//!
+//! [`Stream`]: futures_core::Stream
+
+use alloc::collections::VecDeque;
+use core::{future::poll_fn, pin::Pin};
+
+use futures_core::Stream;
+
+/// A dynamically sized ribbon over a [`Stream`], the asynchronous counterpart of [`Tape`].
+///
+/// Expanding and progressing polls the stream, so these functions are `async`. Peeking and popping
+/// only touch the items currently found on the `AsyncTape`, so they are synchronous.
+///
+/// The stream must be [`Unpin`] to be polled. Streams that are not [`Unpin`] can be pinned with
+/// [`Box::pin`] first.
+///
+/// [`Stream`]: futures_core::Stream
+/// [`Tape`]: crate::Tape
+/// [`Box::pin`]: alloc::boxed::Box::pin
+#[derive(Debug)]
+pub struct AsyncTape<S>
+where
+    S: Stream,
+{
+    stream: S,
+    peeked: Option<S::Item>,
+    /// Set once the wrapped stream returned `None`.
+    done: bool,
+    tape: VecDeque<S::Item>,
+}
+
+impl<S> AsyncTape<S>
+where
+    S: Stream,
+{
+    /// Creates a new `AsyncTape` from the given stream.
+    pub fn new(stream: S) -> AsyncTape<S> {
+        AsyncTape {
+            stream,
+            peeked: None,
+            done: false,
+            tape: VecDeque::new(),
+        }
+    }
+
+    /// Removes the item stored at the head of `AsyncTape` and returns it (if available).
+    pub fn pop_front(&mut self) -> Option<S::Item> {
+        self.tape.pop_front()
+    }
+
+    /// Removes the item stored at the tail of `AsyncTape` and returns it (if available).
+    pub fn pop_back(&mut self) -> Option<S::Item> {
+        self.tape.pop_back()
+    }
+
+    /// Returns a reference to the item stored at the head of `AsyncTape` if item exists.
+    pub fn peek_front(&self) -> Option<&S::Item> {
+        self.tape.front()
+    }
+
+    /// Returns a mutable reference to the item stored at the head of `AsyncTape` if item exists.
+    pub fn peek_front_mut(&mut self) -> Option<&mut S::Item> {
+        self.tape.front_mut()
+    }
+
+    /// Returns a reference to the item stored at the tail of `AsyncTape` if item exists.
+    pub fn peek_back(&self) -> Option<&S::Item> {
+        self.tape.back()
+    }
+
+    /// Returns a mutable reference to the item stored at the tail of `AsyncTape` if item exists.
+    pub fn peek_back_mut(&mut self) -> Option<&mut S::Item> {
+        self.tape.back_mut()
+    }
+
+    /// Returns a reference to the item stored at the given index of `AsyncTape` if item exists.
+    pub fn peek_at(&self, index: usize) -> Option<&S::Item> {
+        self.tape.get(index)
+    }
+
+    /// Returns a mutable reference to the item stored at the given index of `AsyncTape` if item
+    /// exists.
+    pub fn peek_at_mut(&mut self, index: usize) -> Option<&mut S::Item> {
+        self.tape.get_mut(index)
+    }
+
+    /// Returns an iterator over references to the items currently found on the `AsyncTape`, from
+    /// head to tail.
+    pub fn iter(&self) -> impl Iterator<Item = &S::Item> {
+        self.tape.iter()
+    }
+
+    /// Removes all items currently found on the `AsyncTape`. The stream is left intact.
+    pub fn clear(&mut self) {
+        self.tape.clear();
+    }
+
+    /// Returns the number of items currently found on the `AsyncTape`.
+    pub fn len(&self) -> usize {
+        self.tape.len()
+    }
+
+    /// Returns `true` if `AsyncTape` does not contain any items at the moment.
+    pub fn is_empty(&self) -> bool {
+        self.tape.is_empty()
+    }
+
+    /// Consumes the `AsyncTape` and returns the wrapped stream. Items currently found on the
+    /// `AsyncTape` are dropped, including the item rejected by the last call to
+    /// [`expand_while`], if any.
+    ///
+    /// [`expand_while`]: AsyncTape::expand_while
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S> AsyncTape<S>
+where
+    S: Stream + Unpin,
+{
+    /// Returns the next item, taking the one rejected by [`expand_while`] first if available. The
+    /// stream is not polled again once it returned `None`.
+    ///
+    /// [`expand_while`]: AsyncTape::expand_while
+    async fn next_item(&mut self) -> Option<S::Item> {
+        if let Some(item) = self.peeked.take() {
+            return Some(item);
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let next = poll_fn(|cx| Pin::new(&mut self.stream).poll_next(cx)).await;
+        self.done = next.is_none();
+        next
+    }
+
+    /// Streams the stream forward through the `AsyncTape` without expanding it. Returns the head of
+    /// the `AsyncTape`, and the new item from the stream is appended to the tail.
+    ///
+    /// Is a no-op if stream stops producing values. In that case `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures::{executor::block_on, stream};
+    /// use ribbon::stream::AsyncTape;
+    ///
+    /// block_on(async {
+    ///     let mut tape = AsyncTape::new(stream::iter(0..10));
+    ///
+    ///     tape.expand_n(3).await;
+    ///     assert_eq!(tape.progress().await, Some(0));
+    ///     assert_eq!(tape.peek_back(), Some(&3));
+    /// });
+    /// ```
+    pub async fn progress(&mut self) -> Option<S::Item> {
+        let next = self.next_item().await?;
+
+        let head = self.tape.pop_front();
+        self.tape.push_back(next);
+
+        head
+    }
+
+    /// Expands the `AsyncTape` by consuming the next available item and appending it to the tail.
+    /// Returns `true` if `AsyncTape` is expanded.
+    pub async fn expand(&mut self) -> bool {
+        if let Some(item) = self.next_item().await {
+            self.tape.push_back(item);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Expands the `AsyncTape` by consuming the `n` next available items and appending them to the
+    /// end. Returns `true` if `AsyncTape` is expanded by at least one element.
+    pub async fn expand_n(&mut self, n: usize) -> bool {
+        let mut expanded = false;
+
+        for _ in 0..n {
+            if !self.expand().await {
+                break;
+            }
+
+            expanded = true;
+        }
+
+        expanded
+    }
+
+    /// Expands the `AsyncTape` by consuming items from the stream while some condition holds and
+    /// appending them to the end. Returns `true` if `AsyncTape` is expanded by at least one
+    /// element.
+    ///
+    /// The first item for which the condition does not hold is kept aside, and is the next item
+    /// to be appended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures::{executor::block_on, stream};
+    /// use ribbon::stream::AsyncTape;
+    ///
+    /// block_on(async {
+    ///     let mut tape = AsyncTape::new(stream::iter(0..10));
+    ///
+    ///     assert!(tape.expand_while(|item| *item < 5).await);
+    ///     assert_eq!(tape.len(), 5);
+    ///
+    ///     tape.expand().await;
+    ///     assert_eq!(tape.peek_back(), Some(&5));
+    /// });
+    /// ```
+    pub async fn expand_while<F>(&mut self, f: F) -> bool
+    where
+        F: Fn(&S::Item) -> bool,
+    {
+        let mut expanded = false;
+
+        while let Some(item) = self.next_item().await {
+            if !f(&item) {
+                self.peeked = Some(item);
+                break;
+            }
+
+            self.tape.push_back(item);
+            expanded = true;
+        }
+
+        expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream};
+
+    use super::AsyncTape;
+
+    #[test]
+    fn expands() {
+        block_on(async {
+            let mut tape = AsyncTape::new(stream::iter(0..10));
+
+            assert_eq!(tape.peek_front(), None);
+            assert!(tape.expand().await);
+            assert_eq!(tape.peek_front(), Some(&0));
+
+            assert!(tape.expand_n(4).await);
+            assert_eq!(tape.len(), 5);
+            assert_eq!(tape.peek_back(), Some(&4));
+
+            assert!(!tape.expand_while(|item| *item > 5).await);
+            assert!(tape.expand_while(|item| *item < 8).await);
+            assert_eq!(tape.peek_back(), Some(&7));
+
+            assert!(tape.expand_n(5).await);
+            assert_eq!(tape.len(), 10);
+            assert!(!tape.expand().await);
+        });
+    }
+
+    #[test]
+    fn progresses() {
+        block_on(async {
+            let mut tape = AsyncTape::new(stream::iter(0..5));
+            tape.expand_n(3).await;
+
+            assert_eq!(tape.progress().await, Some(0));
+            assert_eq!(tape.progress().await, Some(1));
+            assert_eq!(tape.progress().await, None);
+            assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+            assert_eq!(tape.pop_front(), Some(2));
+            assert_eq!(tape.pop_back(), Some(4));
+            assert_eq!(tape.peek_at(0), Some(&3));
+        });
+    }
+
+    #[test]
+    fn does_not_poll_finished_stream() {
+        block_on(async {
+            // `Unfold` panics when polled after it returned `None`
+            let mut tape = AsyncTape::new(Box::pin(stream::unfold(0, |n| async move {
+                (n < 2).then_some((n, n + 1))
+            })));
+
+            assert!(tape.expand_n(3).await);
+            assert_eq!(tape.len(), 2);
+
+            assert!(!tape.expand().await);
+            assert_eq!(tape.progress().await, None);
+            assert_eq!(tape.len(), 2);
+        });
+    }
+}
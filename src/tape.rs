@@ -2,23 +2,51 @@
 //!
 //! [`Ribbon`]: crate::Ribbon
 
-use std::{collections::VecDeque, iter::Peekable};
+use alloc::{
+    collections::{vec_deque, VecDeque},
+    vec::Vec,
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{Empty, Map, Take},
+    ops::{Index, IndexMut, Range, RangeBounds},
+};
 
-use crate::Ribbon;
+use crate::{ribbon, source::Source, Band, Ribbon, RibbonView};
 
 /// A dynamically sized [`Ribbon`] that can hold varying number of items and can grow and shrink as
 /// necessary. It is backed up by a [`VecDeque`], and allocates memory on the heap (as is customary by
 /// dynamically sized collections)
 ///
-/// [`VecDeque`]: std::collections::VecDeque
+/// # Nested iterators
+///
+/// A `Tape` holds the items of its iterator as they are, so a `Tape` over an iterator of
+/// iterators (e.g. lines of tokens) holds whole inner iterators. To buffer the inner items instead,
+/// flatten the iterator before creating the `Tape`. Expansion then pulls one inner item at a time,
+/// so counts like the one passed to [`expand_n`] refer to the inner items.
+///
+/// ```rust
+/// use ribbon::{Enroll, Ribbon};
+///
+/// let lines = vec![vec![1, 2], vec![3]];
+/// let mut tape = lines.into_iter().flatten().tape();
+///
+/// assert!(tape.expand_n(3));
+/// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// ```
+///
+/// [`VecDeque`]: alloc::collections::VecDeque
+/// [`expand_n`]: crate::Ribbon::expand_n
 /// [`Ribbon`]: crate::Ribbon
-#[derive(Debug)]
 pub struct Tape<I>
 where
     I: Iterator,
 {
-    iter: Peekable<I>,
+    iter: Source<I>,
     tape: VecDeque<I::Item>,
+    /// Maximum number of items the `Tape` is expanded to, if any.
+    max_len: Option<usize>,
 }
 
 impl<I> Tape<I>
@@ -31,8 +59,567 @@ where
         I: Iterator,
     {
         Tape {
-            iter: iter.peekable(),
+            iter: Source::new(iter),
             tape: VecDeque::new(),
+            max_len: None,
+        }
+    }
+
+    /// Creates a new `Tape` from the given iterator, with space for at least `capacity` items.
+    pub fn with_capacity(iter: I, capacity: usize) -> Tape<I> {
+        Tape {
+            iter: Source::new(iter),
+            tape: VecDeque::with_capacity(capacity),
+            max_len: None,
+        }
+    }
+
+    /// Creates a new `Tape` from the given iterator, that stops expanding once it holds `max_len`
+    /// items. This bounds the memory used by the `Tape`, e.g. when [`expand_while`] is called over
+    /// an infinite iterator with a condition that always holds.
+    ///
+    /// Only expansion respects the limit. Items added directly, e.g. with [`push_back`], are
+    /// always appended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::with_max(0.., 3);
+    ///
+    /// assert!(tape.expand_while(|_| true));
+    /// assert_eq!(tape.len(), 3);
+    /// assert!(!tape.expand());
+    ///
+    /// tape.pop_front();
+    /// assert!(tape.expand());
+    /// assert_eq!(tape.peek_back(), Some(&3));
+    /// ```
+    ///
+    /// [`expand_while`]: crate::Ribbon::expand_while
+    /// [`push_back`]: Tape::push_back
+    pub fn with_max(iter: I, max_len: usize) -> Tape<I> {
+        let mut tape = Tape::new(iter);
+        tape.max_len = Some(max_len);
+        tape
+    }
+
+    /// Returns the maximum number of items the `Tape` is expanded to, if any. See
+    /// [`Tape::with_max`].
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Sets the maximum number of items the `Tape` is expanded to. `None` removes the limit. Items
+    /// already found on the `Tape` are kept, even if there are more of them than the limit allows.
+    pub fn set_max_len(&mut self, max_len: Option<usize>) {
+        self.max_len = max_len;
+    }
+
+    /// Creates a new `Tape` from the given iterator, holding the items of the given buffer from
+    /// head to tail. The buffer is reused as is, so no new allocation is made.
+    ///
+    /// Together with [`into_parts`], this allows recycling the buffer of a `Tape`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::from_parts(3..10, VecDeque::from([0, 1, 2]));
+    /// assert_eq!(tape.peek_front(), Some(&0));
+    /// assert_eq!(tape.len(), 3);
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&3));
+    /// ```
+    ///
+    /// [`into_parts`]: Tape::into_parts
+    pub fn from_parts(iter: I, buffer: VecDeque<I::Item>) -> Tape<I> {
+        Tape::from_source(Source::new(iter), buffer)
+    }
+
+    /// Creates a new `Tape` holding the items of the given buffer, that continues from the given
+    /// source, keeping its peeked item.
+    pub(crate) fn from_source(iter: Source<I>, buffer: VecDeque<I::Item>) -> Tape<I> {
+        Tape {
+            iter,
+            tape: buffer,
+            max_len: None,
+        }
+    }
+
+    /// Moves the wrapped iterator and the items currently found on the `Tape` into a new [`Band`]
+    /// with capacity `N`, from head to tail. The `Band` continues from where the `Tape` left off.
+    ///
+    /// The new `Band` uses the default [`Overflow::DropOldest`] policy, so if the items do not fit,
+    /// only the `N` items closest to the tail are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero, since a `Band` without capacity cannot hold any items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Band, Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(4);
+    ///
+    /// let mut band: Band<3, _> = tape.into_band();
+    /// assert_eq!(band.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    ///
+    /// band.expand();
+    /// assert_eq!(band.peek_back(), Some(&4));
+    /// ```
+    ///
+    /// [`Band`]: crate::Band
+    /// [`Overflow::DropOldest`]: crate::Overflow::DropOldest
+    pub fn into_band<const N: usize>(self) -> Band<N, I> {
+        let mut band = Band::from_source(self.iter);
+
+        for item in self.tape {
+            let len = band.len();
+            drop(band.insert_at(len, item));
+        }
+
+        band
+    }
+
+    /// Transforms the `Tape` into one whose items are transformed by the given function. Items
+    /// currently found on the `Tape` are transformed right away, and the items expanded later are
+    /// transformed as they are pulled from the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(2);
+    ///
+    /// let mut tape = tape.map(|item| item * 10);
+    /// assert_eq!(tape.peek_back(), Some(&10));
+    ///
+    /// tape.expand();
+    /// assert_eq!(tape.peek_back(), Some(&20));
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> Tape<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> U,
+    {
+        let tape = self.tape.into_iter().map(&mut f).collect();
+
+        Tape {
+            iter: self.iter.map(f),
+            tape,
+            max_len: self.max_len,
+        }
+    }
+
+    /// Transforms the `Tape` into one that expands by at most `n` more items in total. Items
+    /// currently found on the `Tape` are kept and do not count towards the limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(2);
+    ///
+    /// let mut tape = tape.take(3);
+    /// assert!(tape.expand_n(2));
+    /// assert!(tape.expand_n(2));
+    /// assert!(!tape.expand());
+    /// assert_eq!(tape.len(), 5);
+    /// ```
+    pub fn take(self, n: usize) -> Tape<Take<I>> {
+        Tape {
+            iter: self.iter.take(n),
+            tape: self.tape,
+            max_len: self.max_len,
+        }
+    }
+
+    /// Reserves space for at least `additional` more items to be appended to the `Tape`, so that
+    /// expanding by that many items does not reallocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.tape.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the `Tape` as much as possible, releasing memory that is no longer
+    /// needed after items were removed.
+    pub fn shrink_to_fit(&mut self) {
+        self.tape.shrink_to_fit();
+    }
+
+    /// Returns a pair of slices which contain, in order, the items currently found on the `Tape`.
+    ///
+    /// Where the items are split between the two slices is an implementation detail of the
+    /// underlying ring buffer, and should not be relied upon. Use [`make_contiguous`] to get all
+    /// items in a single slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(5);
+    ///
+    /// let (first, second) = tape.as_slices();
+    /// assert_eq!([first, second].concat(), [0, 1, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`make_contiguous`]: Tape::make_contiguous
+    pub fn as_slices(&self) -> (&[I::Item], &[I::Item]) {
+        self.tape.as_slices()
+    }
+
+    /// Rearranges the items currently found on the `Tape` so that they are stored contiguously,
+    /// and returns them as a single mutable slice, in order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(3);
+    /// tape.push_front(42);
+    ///
+    /// assert_eq!(tape.make_contiguous(), &mut [42, 0, 1, 2]);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [I::Item] {
+        self.tape.make_contiguous()
+    }
+
+    /// Appends the given item to the tail of the `Tape`. The item does not come from the wrapped
+    /// iterator, which is left untouched. Items expanded afterwards are appended after this item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand();
+    /// tape.push_back(42);
+    /// tape.expand();
+    ///
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &42, &1]);
+    /// ```
+    pub fn push_back(&mut self, item: I::Item) {
+        self.tape.push_back(item);
+    }
+
+    /// Prepends the given item to the head of the `Tape`. The item does not come from the wrapped
+    /// iterator, which is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    ///
+    /// tape.expand();
+    /// tape.push_front(42);
+    ///
+    /// assert_eq!(tape.pop_front(), Some(42));
+    /// assert_eq!(tape.pop_front(), Some(0));
+    /// ```
+    pub fn push_front(&mut self, item: I::Item) {
+        self.tape.push_front(item);
+    }
+
+    /// Returns a read-only view into the given range of the items currently found on the `Tape`,
+    /// that can be passed around without moving the `Tape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(6);
+    ///
+    /// let view = tape.view(2..5);
+    /// assert_eq!(view.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+    /// ```
+    pub fn view(&self, range: Range<usize>) -> RibbonView<'_, I::Item> {
+        let (first, second) = self.tape.as_slices();
+        RibbonView::new(first, second, range)
+    }
+
+    /// Returns a reference to the next item the wrapped iterator produces, without appending it to
+    /// the `Tape`. The item is held in an internal buffer, and is appended by the next expansion.
+    ///
+    /// This differs from [`peek_back`], which returns the last item found on the `Tape`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..3);
+    /// tape.expand();
+    ///
+    /// assert_eq!(tape.peek_back(), Some(&0));
+    /// assert_eq!(tape.peek_source(), Some(&1));
+    /// assert_eq!(tape.len(), 1);
+    /// ```
+    ///
+    /// [`peek_back`]: crate::Ribbon::peek_back
+    pub fn peek_source(&mut self) -> Option<&I::Item> {
+        self.iter.peek()
+    }
+
+    /// Splits the `Tape` into two at the given index. Returns a new `Tape` holding the items at
+    /// indices `at..`, while `self` keeps the items at indices `0..at`.
+    ///
+    /// The wrapped iterator stays with `self`, so expanding it continues where it left off. The
+    /// returned `Tape` wraps an [`Empty`] iterator, and expanding it has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the `Tape`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(5);
+    ///
+    /// let back = tape.split_off(3);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    /// assert_eq!(back.iter().collect::<Vec<_>>(), vec![&3, &4]);
+    /// ```
+    ///
+    /// [`Empty`]: core::iter::Empty
+    pub fn split_off(&mut self, at: usize) -> Tape<Empty<I::Item>> {
+        Tape {
+            iter: Source::new(core::iter::empty()),
+            tape: self.tape.split_off(at),
+            max_len: None,
+        }
+    }
+
+    /// Creates a new `Tape` holding clones of the items currently found on the `Tape`, without
+    /// cloning the wrapped iterator. The returned `Tape` wraps an [`Empty`] iterator, so expanding
+    /// it has no effect.
+    ///
+    /// Unlike [`Clone`], this works for iterators that cannot be cloned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(3);
+    ///
+    /// let mut snapshot = tape.clone_buffer();
+    /// assert!(snapshot.content_eq(&tape));
+    ///
+    /// snapshot.pop_front();
+    /// assert_eq!(tape.len(), 3);
+    /// ```
+    ///
+    /// [`Empty`]: core::iter::Empty
+    pub fn clone_buffer(&self) -> Tape<Empty<I::Item>>
+    where
+        I::Item: Clone,
+    {
+        self.tape.iter().cloned().collect()
+    }
+
+    /// Moves all items currently found on `other` to the tail of the `Tape`, leaving `other`
+    /// empty. The wrapped iterators are left untouched, so expanding `other` continues where it
+    /// left off.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// let mut other = Tape::new(10..20);
+    /// tape.expand_n(2);
+    /// other.expand_n(2);
+    ///
+    /// tape.append(&mut other);
+    /// assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &10, &11]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append<J>(&mut self, other: &mut Tape<J>)
+    where
+        J: Iterator<Item = I::Item>,
+    {
+        self.tape.append(&mut other.tape);
+    }
+
+    /// Consumes the `Tape` and returns the wrapped iterator. The returned iterator continues from
+    /// where the `Tape` left off.
+    ///
+    /// Items currently found on the `Tape` are dropped. This includes the item that was pulled from
+    /// the iterator and rejected by the last call to [`expand_while`], if any. Use [`into_parts`]
+    /// to keep them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(3);
+    ///
+    /// let mut iter = tape.into_inner();
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    ///
+    /// [`expand_while`]: crate::Ribbon::expand_while
+    /// [`into_parts`]: Tape::into_parts
+    pub fn into_inner(self) -> I {
+        self.iter.into_parts().1
+    }
+
+    /// Consumes the `Tape` and returns the items currently found on it together with the wrapped
+    /// iterator.
+    ///
+    /// If an item was pulled from the iterator and rejected by the last call to [`expand_while`],
+    /// it is appended to the back of the returned items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(3);
+    ///
+    /// let (items, mut iter) = tape.into_parts();
+    /// assert_eq!(items, [0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    ///
+    /// [`expand_while`]: crate::Ribbon::expand_while
+    pub fn into_parts(self) -> (VecDeque<I::Item>, I) {
+        let mut tape = self.tape;
+        let (peeked, iter) = self.iter.into_parts();
+
+        tape.extend(peeked);
+
+        (tape, iter)
+    }
+
+    /// Consumes the `Tape` and returns an iterator over the items currently found on it, from head
+    /// to tail. The wrapped iterator is dropped, so no further items are pulled from it.
+    ///
+    /// In contrast, iterating over the `Tape` itself (e.g. `for item in tape {}`) yields the items
+    /// found on the `Tape` first, and then continues pulling items from the wrapped iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new(0..10);
+    /// tape.expand_n(3);
+    ///
+    /// let items: Vec<_> = tape.into_buffered_iter().collect();
+    /// assert_eq!(items, vec![0, 1, 2]);
+    /// ```
+    pub fn into_buffered_iter(self) -> impl Iterator<Item = I::Item> {
+        self.tape.into_iter()
+    }
+
+    /// Saves the current state of the `Tape`, so that it can be restored with [`restore`] later.
+    /// Useful for backtracking, e.g. when a parser has to rewind after a failed alternative.
+    ///
+    /// The [`Checkpoint`] holds a clone of the items currently found on the `Tape` and of the
+    /// wrapped iterator. Keeping it alive therefore costs as much memory as the buffered items, and
+    /// items popped after the checkpoint are read again by pulling them from the cloned iterator
+    /// after restoring. This is only sound for iterators whose clones produce the same items, which
+    /// is the case for iterators over collections, ranges, `Chars` etc.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ribbon::{Ribbon, Tape};
+    ///
+    /// let mut tape = Tape::new("let x".chars());
+    /// tape.expand_n(2);
+    ///
+    /// let checkpoint = tape.checkpoint();
+    /// assert_eq!(tape.by_ref().take(3).collect::<String>(), "let");
+    ///
+    /// tape.restore(checkpoint);
+    /// assert_eq!(tape.len(), 2);
+    /// assert_eq!(tape.collect::<String>(), "let x");
+    /// ```
+    ///
+    /// [`restore`]: Tape::restore
+    pub fn checkpoint(&self) -> Checkpoint<I>
+    where
+        I: Clone,
+        I::Item: Clone,
+    {
+        Checkpoint { tape: self.clone() }
+    }
+
+    /// Restores the `Tape` to the state saved by [`checkpoint`]. Items popped since then are read
+    /// again, and items expanded since then are dropped.
+    ///
+    /// [`checkpoint`]: Tape::checkpoint
+    pub fn restore(&mut self, checkpoint: Checkpoint<I>) {
+        *self = checkpoint.tape;
+    }
+}
+
+/// State of a [`Tape`] saved by [`Tape::checkpoint`], that can be restored with [`Tape::restore`].
+pub struct Checkpoint<I>
+where
+    I: Iterator,
+{
+    tape: Tape<I>,
+}
+
+impl<I> fmt::Debug for Checkpoint<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checkpoint")
+            .field("tape", &self.tape)
+            .finish()
+    }
+}
+
+impl<I> Clone for Checkpoint<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tape: self.tape.clone(),
         }
     }
 }
@@ -44,6 +631,7 @@ where
     fn progress(&mut self) -> Option<I::Item> {
         let next = self.iter.next()?;
 
+        // pop first, so that the new item reuses the freed slot instead of growing the buffer
         let head = self.pop_front();
         self.tape.push_back(next);
 
@@ -51,6 +639,10 @@ where
     }
 
     fn expand(&mut self) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
         if let Some(item) = self.iter.next() {
             self.tape.push_back(item);
             true
@@ -59,23 +651,94 @@ where
         }
     }
 
-    fn expand_while<F>(&mut self, f: F) -> bool
+    /// Expands the `Tape` by consuming the `n` next available item and appending them to the end.
+    /// Returns `true` if `Tape` is expanded by at least one element.
+    ///
+    /// Space for the items is reserved up front, as far as the iterator guarantees to produce them.
+    fn expand_n(&mut self, n: usize) -> bool {
+        let (lower, _) = self.iter.size_hint();
+        let room = self.max_len.map_or(usize::MAX, |max_len| {
+            max_len.saturating_sub(self.tape.len())
+        });
+        self.tape.reserve(n.min(lower).min(room));
+
+        let mut expanded = false;
+        for _ in 0..n {
+            if !self.expand() {
+                break;
+            }
+
+            expanded = true;
+        }
+
+        expanded
+    }
+
+    fn expand_step_by(&mut self, n: usize, step: usize) -> bool {
+        assert!(step != 0, "step must be non-zero");
+
+        let mut expanded = false;
+
+        for i in 0..n {
+            // do not skip items that could not be appended anyway
+            if self.is_full() {
+                break;
+            }
+
+            if i > 0 {
+                self.iter.discard(step - 1);
+            }
+
+            if !self.expand() {
+                break;
+            }
+
+            expanded = true;
+        }
+
+        expanded
+    }
+
+    fn expand_while_indexed<F>(&mut self, f: F) -> bool
     where
-        F: Fn(&I::Item) -> bool,
+        F: Fn(usize, &I::Item) -> bool,
     {
-        let mut expanded = false;
+        let mut count = 0;
 
         loop {
             match self.iter.peek() {
-                Some(item) if f(item) => {
-                    expanded = true;
-                    self.expand();
+                Some(item) if f(count, item) => {
+                    if !self.expand() {
+                        break;
+                    }
+
+                    count += 1;
                 }
                 _ => break,
             }
         }
 
-        expanded
+        count > 0
+    }
+
+    fn expand_while_peek<F>(&mut self, f: F) -> (bool, Option<&I::Item>)
+    where
+        F: Fn(&I::Item) -> bool,
+    {
+        let expanded = self.expand_while(f);
+        (expanded, self.iter.peek())
+    }
+
+    fn source_exhausted(&mut self) -> bool {
+        self.iter.peek().is_none()
+    }
+
+    fn seal(&mut self) {
+        self.iter.seal();
+    }
+
+    fn expand_take(&mut self, n: usize) {
+        self.iter.limit(n);
     }
 
     fn pop_front(&mut self) -> Option<I::Item> {
@@ -110,11 +773,109 @@ where
         self.tape.get_mut(index)
     }
 
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a I::Item>
+    where
+        I::Item: 'a,
+    {
+        self.tape.iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut I::Item>
+    where
+        I::Item: 'a,
+    {
+        self.tape.iter_mut()
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.tape.truncate(len);
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&I::Item) -> bool,
+    {
+        self.tape.retain(f);
+    }
+
+    fn dedup_buffered(&mut self)
+    where
+        I::Item: PartialEq,
+    {
+        let mut deduped = VecDeque::with_capacity(self.tape.len());
+
+        for item in self.tape.drain(..) {
+            if deduped.back() != Some(&item) {
+                deduped.push_back(item);
+            }
+        }
+
+        self.tape = deduped;
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.tape.swap(i, j);
+    }
+
+    fn insert_at(&mut self, index: usize, value: I::Item) -> Option<I::Item> {
+        self.tape.insert(index, value);
+        None
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<I::Item> {
+        self.tape.remove(index)
+    }
+
+    fn get_disjoint_mut(&mut self, i: usize, j: usize) -> Option<(&mut I::Item, &mut I::Item)> {
+        let (first, second) = self.tape.as_mut_slices();
+        ribbon::get_disjoint_mut(first, second, i, j)
+    }
+
+    fn drain<R>(&mut self, range: R) -> impl Iterator<Item = I::Item>
+    where
+        Self: Sized,
+        R: RangeBounds<usize>,
+    {
+        self.tape.drain(range)
+    }
+
+    fn rotate_left(&mut self, n: usize) {
+        self.tape.rotate_left(n);
+    }
+
+    fn rotate_right(&mut self, n: usize) {
+        self.tape.rotate_right(n);
+    }
+
+    fn clear(&mut self) {
+        self.tape.clear();
+    }
+
+    fn capacity(&self) -> usize {
+        self.tape.capacity()
+    }
+
+    /// Returns `true` if the `Tape` holds as many items as it is allowed to expand to, see
+    /// [`Tape::with_max`]. A `Tape` without such a limit is never full.
+    fn is_full(&self) -> bool {
+        self.max_len
+            .is_some_and(|max_len| self.tape.len() >= max_len)
+    }
+
     fn len(&self) -> usize {
         self.tape.len()
     }
 }
 
+impl<I> Default for Tape<I>
+where
+    I: Iterator + Default,
+{
+    fn default() -> Self {
+        Tape::new(I::default())
+    }
+}
+
 impl<I> From<I> for Tape<I>
 where
     I: Iterator,
@@ -124,61 +885,288 @@ where
     }
 }
 
-impl<I> Iterator for Tape<I>
+impl<I> Index<usize> for Tape<I>
 where
     I: Iterator,
 {
-    type Item = I::Item;
+    type Output = I::Item;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.is_empty() {
-            self.expand();
-        }
-
-        self.pop_front()
+    /// Returns a reference to the item at the given index of the `Tape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.tape[index]
     }
 }
 
-impl<I> Clone for Tape<I>
+impl<I> IndexMut<usize> for Tape<I>
 where
-    I: Iterator + Clone,
-    I::Item: Clone,
+    I: Iterator,
 {
-    fn clone(&self) -> Self {
-        Self {
-            iter: self.iter.clone(),
-            tape: self.tape.clone(),
-        }
+    /// Returns a mutable reference to the item at the given index of the `Tape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.tape[index]
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::ribbon::Ribbon;
-    use crate::tape::Tape;
+/// Iterates over references to the items currently found on the `Tape`, from head to tail. Items
+/// are not consumed, and the `Tape` is not expanded.
+impl<'a, I> IntoIterator for &'a Tape<I>
+where
+    I: Iterator,
+{
+    type Item = &'a I::Item;
+    type IntoIter = vec_deque::Iter<'a, I::Item>;
 
-    #[test]
-    fn expands() {
-        let mut tape = Tape::new(0u32..10u32);
+    fn into_iter(self) -> Self::IntoIter {
+        self.tape.iter()
+    }
+}
 
-        assert_eq!(tape.peek_front(), None);
-        assert_eq!(tape.peek_back(), None);
+/// Collects all items into the `Tape` eagerly.
+///
+/// Unlike [`Tape::new`], which pulls items lazily, the resulting `Tape` holds every item produced
+/// by the iterator, and wraps an [`Empty`] iterator. Expanding it has no effect.
+///
+/// # Example
+///
+/// ```rust
+/// use ribbon::{Ribbon, Tape};
+///
+/// let mut tape: Tape<_> = (0..5).collect();
+///
+/// assert_eq!(tape.len(), 5);
+/// assert_eq!(tape.expand(), false);
+/// ```
+///
+/// [`Empty`]: core::iter::Empty
+impl<T> FromIterator<T> for Tape<Empty<T>> {
+    fn from_iter<It>(iter: It) -> Self
+    where
+        It: IntoIterator<Item = T>,
+    {
+        Tape {
+            iter: Source::new(core::iter::empty()),
+            tape: VecDeque::from_iter(iter),
+            max_len: None,
+        }
+    }
+}
 
-        tape.expand();
-        assert_eq!(tape.peek_front(), Some(&0));
-        assert_eq!(tape.peek_back(), Some(&0));
+/// Appends the items to the tail of the `Tape`, bypassing the wrapped iterator. Items expanded
+/// afterwards are appended after these items.
+impl<I> Extend<I::Item> for Tape<I>
+where
+    I: Iterator,
+{
+    fn extend<It>(&mut self, iter: It)
+    where
+        It: IntoIterator<Item = I::Item>,
+    {
+        self.tape.extend(iter);
+    }
+}
 
-        tape.expand();
-        assert_eq!(tape.peek_front(), Some(&0));
-        assert_eq!(tape.peek_back(), Some(&1));
+/// Compares the items currently found on the `Tape`s, from head to tail. The wrapped iterators are
+/// not taken into account.
+impl<I> PartialEq for Tape<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tape == other.tape
     }
+}
 
-    #[test]
-    fn pops_front() {
-        let mut tape = Tape::new(0..10);
-        tape.expand_n(5);
+impl<I> Eq for Tape<I>
+where
+    I: Iterator,
+    I::Item: Eq,
+{
+}
 
-        assert_eq!(tape.pop_front(), Some(0));
+/// Compares the items currently found on the `Tape`, from head to tail, with the items of the slice.
+impl<I, U> PartialEq<[U]> for Tape<I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(item, other)| item == other)
+    }
+}
+
+impl<I, U> PartialEq<&[U]> for Tape<I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self == *other
+    }
+}
+
+impl<I, U, const N: usize> PartialEq<[U; N]> for Tape<I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self == &other[..]
+    }
+}
+
+impl<I, U> PartialEq<Vec<U>> for Tape<I>
+where
+    I: Iterator,
+    I::Item: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+/// Hashes the items currently found on the `Tape`, from head to tail, consistently with
+/// [`PartialEq`].
+impl<I> Hash for Tape<I>
+where
+    I: Iterator,
+    I::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.tape.hash(state);
+    }
+}
+
+/// Yields the items found on the `Tape` from head to tail, expanding the `Tape` whenever it is
+/// empty. Iterating over the `Tape` therefore yields the items found on it, followed by the
+/// remaining items of the wrapped iterator. Use [`Tape::into_buffered_iter`] to only yield the items
+/// found on the `Tape`.
+impl<I> Iterator for Tape<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pop_front() {
+            return Some(item);
+        }
+
+        // an item appended to the empty `Tape` would be removed right away, so skip the buffer
+        if self.is_full() {
+            return None;
+        }
+
+        self.iter.next()
+    }
+}
+
+/// Prints the items currently found on the `Tape`, from head to tail. The wrapped iterator is not
+/// printed, so it does not need to implement `Debug`.
+impl<I> fmt::Debug for Tape<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tape")
+            .field("items", &self.tape)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I> Clone for Tape<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            tape: self.tape.clone(),
+            max_len: self.max_len,
+        }
+    }
+}
+
+/// Serializes the items currently found on the `Tape`, from head to tail. The wrapped iterator is
+/// not serialized.
+#[cfg(feature = "serde")]
+impl<I> serde::Serialize for Tape<I>
+where
+    I: Iterator,
+    I::Item: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(&self.tape)
+    }
+}
+
+/// Deserializes the items found on the `Tape`, from head to tail. Since the iterator is not
+/// serialized, the `Tape` wraps the [`Default`] value of the iterator.
+///
+/// Round-trip preserves the items found on the `Tape`, but not the items the iterator would produce.
+#[cfg(feature = "serde")]
+impl<'de, I> serde::Deserialize<'de> for Tape<I>
+where
+    I: Iterator + Default,
+    I::Item: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tape = VecDeque::deserialize(deserializer)?;
+
+        Ok(Tape {
+            iter: Source::new(I::default()),
+            tape,
+            max_len: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::ribbon::Ribbon;
+    use crate::tape::Tape;
+    use crate::Band;
+
+    #[test]
+    fn expands() {
+        let mut tape = Tape::new(0u32..10u32);
+
+        assert_eq!(tape.peek_front(), None);
+        assert_eq!(tape.peek_back(), None);
+
+        tape.expand();
+        assert_eq!(tape.peek_front(), Some(&0));
+        assert_eq!(tape.peek_back(), Some(&0));
+
+        tape.expand();
+        assert_eq!(tape.peek_front(), Some(&0));
+        assert_eq!(tape.peek_back(), Some(&1));
+    }
+
+    #[test]
+    fn pops_front() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.pop_front(), Some(0));
         assert_eq!(tape.pop_front(), Some(1));
         assert_eq!(tape.pop_front(), Some(2));
         assert_eq!(tape.pop_front(), Some(3));
@@ -235,6 +1223,1192 @@ mod tests {
         assert_eq!(tape.len(), 0);
     }
 
+    #[test]
+    fn iterates_buffered() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+        tape.pop_front();
+
+        let items: Vec<_> = tape.iter().copied().collect();
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(tape.len(), 4);
+    }
+
+    #[test]
+    fn mutates_buffered() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        for item in tape.iter_mut() {
+            *item += 1;
+        }
+
+        assert_eq!(tape.peek_at(0), Some(&1));
+        assert_eq!(tape.peek_at(1), Some(&2));
+        assert_eq!(tape.peek_at(2), Some(&3));
+    }
+
+    #[test]
+    fn into_inner_continues() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+        tape.progress();
+
+        let rest: Vec<_> = tape.into_inner().collect();
+        assert_eq!(rest, vec![4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn into_buffered_iter() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        let items: Vec<_> = tape.clone().into_buffered_iter().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+
+        // iterating over the tape itself continues with the wrapped iterator
+        let items: Vec<_> = tape.collect();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+
+        let mut drained = Tape::new(0..10);
+        drained.expand_n(3);
+        drained.drain_front(3).for_each(drop);
+        assert_eq!(drained.into_buffered_iter().next(), None);
+    }
+
+    #[test]
+    fn collects_remaining() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(4);
+        tape.drain_front(2).for_each(drop);
+
+        let items: Vec<_> = tape.collect_remaining();
+        assert_eq!(items, (2..10).collect::<Vec<_>>());
+
+        let tape = Tape::new(0..5);
+        let items: Vec<_> = tape.collect_remaining();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn restores_checkpoint() {
+        let mut tape = Tape::new([1, 2, 3, 4, 5, 6].iter());
+        tape.expand_n(2);
+        tape.pop_front();
+
+        let checkpoint = tape.checkpoint();
+
+        // pop more items than were buffered at the checkpoint
+        let popped: Vec<_> = tape.by_ref().take(4).collect();
+        assert_eq!(popped, vec![&2, &3, &4, &5]);
+
+        tape.restore(checkpoint.clone());
+        assert_eq!(tape.len(), 1);
+        assert_eq!(tape.peek_front(), Some(&&2));
+
+        let reread: Vec<_> = tape.by_ref().take(4).collect();
+        assert_eq!(reread, popped);
+
+        // a checkpoint can be restored multiple times
+        tape.restore(checkpoint);
+        assert_eq!(tape.collect::<Vec<_>>(), vec![&2, &3, &4, &5, &6]);
+    }
+
+    #[test]
+    fn splits_off() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(6);
+
+        let mut back = tape.split_off(3);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+        assert_eq!(back.iter().collect::<Vec<_>>(), vec![&3, &4, &5]);
+
+        // the source iterator stays with the front half
+        assert!(tape.expand());
+        assert_eq!(tape.peek_back(), Some(&6));
+        assert!(!back.expand());
+
+        let rest = tape.split_off(tape.len());
+        assert!(rest.is_empty());
+        assert_eq!(tape.len(), 4);
+    }
+
+    #[test]
+    fn appends() {
+        let mut tape = Tape::new(0..10);
+        let mut other = Tape::new(10..20);
+        tape.expand_n(3);
+        other.expand_n(2);
+
+        tape.append(&mut other);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &10, &11]);
+        assert!(other.is_empty());
+
+        // the wrapped iterators are left intact
+        assert!(tape.expand());
+        assert_eq!(tape.peek_back(), Some(&3));
+        assert!(other.expand());
+        assert_eq!(other.peek_back(), Some(&12));
+
+        // tapes with different sources can be appended, e.g. split off halves
+        let mut back = tape.split_off(2);
+        back.append(&mut tape);
+        assert_eq!(
+            back.iter().collect::<Vec<_>>(),
+            vec![&2, &10, &11, &3, &0, &1]
+        );
+        assert!(tape.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(2);
+        tape.split_off(3);
+    }
+
+    #[test]
+    fn clones_buffer() {
+        /// Iterator that cannot be cloned.
+        struct Counter(u32);
+
+        impl Iterator for Counter {
+            type Item = u32;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0 += 1;
+                Some(self.0)
+            }
+        }
+
+        let mut tape = Tape::new(Counter(0));
+        tape.expand_n(3);
+
+        let mut snapshot = tape.clone_buffer();
+        assert!(snapshot.iter().eq(tape.iter()));
+
+        // snapshot is independent of the original tape
+        assert!(!snapshot.expand());
+        assert_eq!(snapshot.pop_front(), Some(1));
+        assert_eq!(tape.len(), 3);
+
+        assert!(tape.expand());
+        assert_eq!(tape.peek_back(), Some(&4));
+        assert_eq!(snapshot.len(), 2);
+    }
+
+    #[test]
+    fn ends() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape.ends(), (None, None));
+        assert_eq!(tape.ends_mut(), (None, None));
+
+        // a single item is both the head and the tail, but is not aliased mutably
+        tape.expand();
+        assert_eq!(tape.ends(), (Some(&0), Some(&0)));
+        assert_eq!(tape.ends_mut(), (Some(&mut 0), None));
+
+        tape.expand_n(3);
+        assert_eq!(tape.ends(), (Some(&0), Some(&3)));
+
+        if let (Some(front), Some(back)) = tape.ends_mut() {
+            *front = 10;
+            *back = 30;
+        }
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&10, &1, &2, &30]);
+    }
+
+    #[test]
+    fn source_exhausted() {
+        let mut tape = Tape::new(0..3);
+        assert!(!tape.source_exhausted());
+
+        tape.expand_n(3);
+        assert!(tape.source_exhausted());
+        assert_eq!(tape.len(), 3);
+
+        // peeked item is not lost
+        let mut tape = Tape::new(0..3);
+        tape.expand();
+        assert!(!tape.source_exhausted());
+        assert_eq!(tape.into_parts().0, [0, 1]);
+    }
+
+    #[test]
+    fn stays_drained() {
+        /// Iterator that resumes producing items after returning `None`.
+        struct Flaky(u32);
+
+        impl Iterator for Flaky {
+            type Item = u32;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0 += 1;
+                (self.0 != 3).then_some(self.0)
+            }
+        }
+
+        let mut tape = Tape::new(Flaky(0));
+        assert!(tape.expand_n(2));
+        assert!(!tape.expand());
+        assert!(!tape.expand());
+        assert!(tape.progress().is_none());
+        assert!(tape.source_exhausted());
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn takes() {
+        let mut tape = Tape::new(0..10);
+        tape.expand();
+        assert_eq!(tape.peek_source(), Some(&1));
+
+        // budget is respected across expansions, including the peeked item
+        let mut tape = tape.take(4);
+        assert!(tape.expand());
+        assert!(tape.expand_n(2));
+        assert_eq!(tape.expand_n_counted(5), 1);
+        assert!(!tape.expand());
+        assert!(tape.progress().is_none());
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &3, &4]);
+
+        let mut tape = Tape::new(0..10).take(0);
+        assert!(!tape.expand());
+    }
+
+    #[test]
+    fn expand_takes() {
+        let mut tape = Tape::new(0..10);
+        tape.expand();
+        assert!(!tape.expand_while(|item| *item < 1));
+
+        // budget is respected across expansions, including the peeked item
+        tape.expand_take(4);
+        assert!(tape.expand());
+        assert!(tape.expand_n(2));
+        assert_eq!(tape.progress(), Some(0));
+        assert!(!tape.expand());
+        assert!(tape.progress().is_none());
+        assert!(tape.source_exhausted());
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+
+        // a new budget replaces the spent one
+        tape.expand_take(2);
+        assert_eq!(tape.expand_n_counted(5), 2);
+        assert_eq!(tape.peek_back(), Some(&6));
+
+        // through a trait object
+        let mut ribbon: crate::DynRibbon<'_, u32> = Box::new(Tape::new(0..10));
+        ribbon.expand_take(0);
+        assert!(!ribbon.expand());
+    }
+
+    #[test]
+    fn expands_while_peek() {
+        let mut tape = Tape::new(0..6);
+
+        assert_eq!(tape.expand_while_peek(|item| *item < 3), (true, Some(&3)));
+        assert_eq!(tape.peek_back(), Some(&2));
+
+        // the peeked item was not appended
+        assert_eq!(tape.expand_while_peek(|item| *item < 3), (false, Some(&3)));
+        assert_eq!(tape.len(), 3);
+
+        assert_eq!(tape.expand_while_peek(|_| true), (true, None));
+        assert_eq!(tape.len(), 6);
+    }
+
+    #[test]
+    fn replaces_at() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.replace_at(0, 10), Some(0));
+        assert_eq!(tape.replace_at(2, 12), Some(2));
+        assert_eq!(tape.replace_at(4, 14), Some(4));
+        assert_eq!(tape.replace_at(5, 15), None);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&10, &1, &12, &3, &14]);
+        assert_eq!(tape.len(), 5);
+    }
+
+    #[test]
+    fn views() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(6);
+
+        let view = tape.view(2..5);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.peek_front(), Some(&2));
+        assert_eq!(view.peek_back(), Some(&4));
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        // the tape is unchanged
+        assert_eq!(tape.len(), 6);
+        assert!(tape.view(6..6).is_empty());
+    }
+
+    #[test]
+    fn peeks_source() {
+        let mut tape = Tape::new(0..3);
+        assert_eq!(tape.peek_back(), None);
+        assert_eq!(tape.peek_source(), Some(&0));
+
+        // expand only if the next item matches
+        if tape.peek_source() == Some(&0) {
+            tape.expand();
+        }
+        assert_eq!(tape.peek_back(), Some(&0));
+        assert_eq!(tape.peek_source(), Some(&1));
+        assert_eq!(tape.len(), 1);
+
+        tape.expand_n(2);
+        assert_eq!(tape.peek_back(), Some(&2));
+        assert_eq!(tape.peek_source(), None);
+    }
+
+    #[test]
+    fn peeks_back_at() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape.peek_back_at(0), None);
+
+        tape.expand_n(5);
+        assert_eq!(tape.peek_back_at(0), tape.peek_back());
+        assert_eq!(tape.peek_back_at(1), Some(&3));
+        assert_eq!(tape.peek_back_at(4), Some(&0));
+        assert_eq!(tape.peek_back_at(5), None);
+        assert_eq!(tape.peek_back_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn expands_n_counted() {
+        let mut tape = Tape::new(0..5);
+
+        assert_eq!(tape.expand_n_counted(0), 0);
+        assert_eq!(tape.expand_n_counted(3), 3);
+        assert_eq!(tape.expand_n_counted(3), 2);
+        assert_eq!(tape.expand_n_counted(3), 0);
+        assert_eq!(tape.len(), 5);
+    }
+
+    #[test]
+    fn iterates_by_reference() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        let mut items = Vec::new();
+        for item in &tape {
+            items.push(*item);
+        }
+
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(tape.len(), 3);
+        assert_eq!(tape.peek_front(), Some(&0));
+    }
+
+    #[test]
+    fn from_parts() {
+        let mut tape = Tape::from_parts(0..3, VecDeque::from([7, 8]));
+        assert_eq!(tape.peek_front(), Some(&7));
+        assert_eq!(tape.peek_back(), Some(&8));
+        assert_eq!(tape.len(), 2);
+
+        tape.expand();
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&7, &8, &0]);
+
+        // the buffer can be recycled, keeping its allocation
+        let (mut buffer, _) = tape.into_parts();
+        buffer.clear();
+        let capacity = buffer.capacity();
+
+        let mut tape = Tape::from_parts(10..20, buffer);
+        assert!(tape.is_empty());
+        assert_eq!(tape.capacity(), capacity);
+
+        tape.expand_n(2);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&10, &11]);
+    }
+
+    #[test]
+    fn into_parts_keeps_rejected() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_while(|item| *item < 3);
+
+        let (items, iter) = tape.into_parts();
+        assert_eq!(items, [0, 1, 2, 3]);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn clears() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        tape.clear();
+        assert_eq!(tape.len(), 0);
+        assert!(tape.is_empty());
+
+        tape.expand_n(2);
+        assert_eq!(tape.peek_front(), Some(&5));
+        assert_eq!(tape.peek_back(), Some(&6));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut tape = Tape::new(0u32..10u32);
+        tape.expand_n(4);
+        tape.pop_front();
+
+        let json = serde_json::to_string(&tape).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let restored: Tape<std::ops::Range<u32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.peek_at(0), tape.peek_at(0));
+        assert_eq!(restored.peek_at(1), tape.peek_at(1));
+        assert_eq!(restored.peek_at(2), tape.peek_at(2));
+    }
+
+    #[test]
+    fn peeks_n() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.peek_front_n(3).collect::<Vec<_>>(), vec![&0, &1, &2]);
+        assert_eq!(tape.peek_back_n(3).collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(tape.peek_front_n(10).count(), 5);
+        assert_eq!(tape.peek_back_n(10).count(), 5);
+        assert_eq!(tape.len(), 5);
+    }
+
+    #[test]
+    fn peeks_range() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.peek_range(1..3).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(tape.peek_range(1..=3).collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(tape.peek_range(..2).collect::<Vec<_>>(), vec![&0, &1]);
+        assert_eq!(tape.peek_range(3..).collect::<Vec<_>>(), vec![&3, &4]);
+        assert_eq!(tape.peek_range(..).count(), 5);
+        assert_eq!(tape.peek_range(3..10).collect::<Vec<_>>(), vec![&3, &4]);
+        assert_eq!(tape.peek_range(7..).count(), 0);
+        assert_eq!(tape.len(), 5);
+    }
+
+    #[test]
+    fn expands_until() {
+        let mut tape = Tape::new([1, 2, 0, 3, 4, 0, 5].into_iter());
+
+        assert!(tape.expand_until(|item| *item == 0));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &0]);
+
+        tape.clear();
+        assert!(tape.expand_until(|item| *item == 0));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&3, &4, &0]);
+
+        tape.clear();
+        assert!(tape.expand_until(|item| *item == 0));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&5]);
+
+        assert!(!tape.expand_until(|item| *item == 0));
+    }
+
+    #[test]
+    fn progress_reuses_capacity() {
+        let mut tape = Tape::with_capacity(0..10_000, 4);
+        tape.expand_n(4);
+        let capacity = tape.capacity();
+
+        for i in 0..5_000 {
+            assert_eq!(tape.progress(), Some(i));
+        }
+
+        assert_eq!(tape.capacity(), capacity);
+        assert_eq!(tape.len(), 4);
+        assert_eq!(tape.peek_front(), Some(&5_000));
+        assert_eq!(tape.peek_back(), Some(&5_003));
+    }
+
+    #[test]
+    fn progress_on_empty_tape() {
+        let mut tape = Tape::new(0..2);
+
+        assert_eq!(tape.progress(), None);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0]);
+
+        assert_eq!(tape.progress(), Some(0));
+        assert_eq!(tape.progress(), None);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1]);
+    }
+
+    #[test]
+    fn progresses_n() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        assert_eq!(tape.progress_n(4).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+
+        // iterator is exhausted after 3 more items
+        assert_eq!(tape.progress_n(5).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&7, &8, &9]);
+    }
+
+    #[test]
+    fn indexes() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape[0], 0);
+        assert_eq!(tape[4], 4);
+
+        tape[2] = 42;
+        assert_eq!(tape[2], 42);
+        assert_eq!(tape.peek_at(2), Some(&42));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        let _ = tape[5];
+    }
+
+    #[test]
+    fn collects() {
+        let collected: Tape<_> = (0..5).collect();
+
+        let mut expanded = Tape::new(0..5);
+        expanded.expand_n(5);
+
+        assert_eq!(collected.len(), expanded.len());
+        assert!(collected.iter().eq(expanded.iter()));
+    }
+
+    #[test]
+    fn injects_items() {
+        let mut tape = Tape::new(0..10);
+
+        tape.expand();
+        tape.push_back(100);
+        tape.expand();
+        tape.extend([200, 300]);
+        tape.push_front(-1);
+        tape.expand();
+
+        assert_eq!(tape.pop_front(), Some(-1));
+        assert_eq!(tape.pop_front(), Some(0));
+        assert_eq!(tape.pop_front(), Some(100));
+        assert_eq!(tape.pop_front(), Some(1));
+        assert_eq!(tape.pop_front(), Some(200));
+        assert_eq!(tape.pop_front(), Some(300));
+        assert_eq!(tape.pop_front(), Some(2));
+        assert_eq!(tape.pop_front(), None);
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut tape = Tape::with_capacity(0..10, 16);
+        assert!(tape.capacity() >= 16);
+
+        let capacity = tape.capacity();
+        tape.expand_n(10);
+        assert_eq!(tape.capacity(), capacity);
+    }
+
+    #[test]
+    fn reserves_and_shrinks() {
+        let mut tape = Tape::new(0..100);
+
+        tape.reserve(100);
+        let capacity = tape.capacity();
+        assert!(capacity >= 100);
+
+        tape.expand_n(100);
+        assert_eq!(tape.capacity(), capacity);
+
+        while tape.len() > 10 {
+            tape.pop_front();
+        }
+
+        tape.shrink_to_fit();
+        assert!(tape.capacity() < capacity);
+        assert!(tape.capacity() >= 10);
+    }
+
+    #[test]
+    fn drains_front() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.drain_front(2).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        assert_eq!(tape.drain_front(10).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert!(tape.is_empty());
+
+        tape.expand();
+        assert_eq!(tape.peek_front(), Some(&5));
+    }
+
+    #[test]
+    fn expands_while_indexed() {
+        let mut tape = Tape::new([1, 2, 3, 4, 5, 6, 0].into_iter());
+
+        // values keep ascending, but the index stops the expansion
+        assert!(tape.expand_while_indexed(|i, item| i < 4 && *item > 0));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+
+        // index starts from zero on every call
+        assert!(tape.expand_while_indexed(|i, item| i < 4 && *item > 0));
+        assert_eq!(
+            tape.iter().collect::<Vec<_>>(),
+            vec![&1, &2, &3, &4, &5, &6]
+        );
+
+        assert!(!tape.expand_while_indexed(|_, item| *item > 0));
+    }
+
+    #[test]
+    fn as_slices_in_order() {
+        let mut tape = Tape::with_capacity(0..10, 4);
+        tape.expand_n(4);
+        tape.pop_front();
+        tape.pop_front();
+        tape.push_front(-1);
+        tape.expand();
+
+        let expected: Vec<_> = tape.iter().copied().collect();
+        let (first, second) = tape.as_slices();
+        assert_eq!([first, second].concat(), expected);
+
+        assert_eq!(tape.make_contiguous(), expected.as_slice());
+        assert_eq!(tape.as_slices().0, expected.as_slice());
+    }
+
+    #[test]
+    fn hashes_buffered() {
+        use std::hash::BuildHasher;
+
+        let hasher = std::collections::hash_map::RandomState::new();
+
+        let mut progressed = Tape::new(0..10);
+        progressed.expand_n(3);
+        progressed.progress_n(2).for_each(drop);
+
+        let mut expanded = Tape::new(2..5);
+        expanded.expand_n(3);
+
+        assert_eq!(progressed, expanded);
+        assert_eq!(hasher.hash_one(&progressed), hasher.hash_one(&expanded));
+
+        expanded.pop_back();
+        assert_ne!(progressed, expanded);
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Lexer {
+            tape: Tape<std::iter::Empty<char>>,
+        }
+
+        let mut lexer = Lexer::default();
+        assert_eq!(lexer.tape.len(), 0);
+        assert!(lexer.tape.is_empty());
+        assert!(!lexer.tape.expand());
+    }
+
+    #[test]
+    fn maps() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_while(|item| *item < 2);
+
+        let mut tape = tape.map(|item| item.to_string());
+        assert_eq!(tape.len(), 2);
+        assert_eq!(tape.peek_front(), Some(&String::from("0")));
+
+        // item rejected by `expand_while` is not lost
+        tape.expand_n(2);
+        assert_eq!(tape.peek_at(2), Some(&String::from("2")));
+        assert_eq!(tape.peek_back(), Some(&String::from("3")));
+        assert_eq!(tape.progress(), Some(String::from("0")));
+    }
+
+    #[test]
+    fn rotates() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        tape.rotate_left(2);
+        assert_eq!(tape.peek_front(), Some(&2));
+        assert_eq!(tape.peek_back(), Some(&1));
+
+        tape.rotate_right(3);
+        assert_eq!(tape.peek_front(), Some(&4));
+        assert_eq!(tape.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn retains() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(8);
+
+        tape.retain(|item| item % 2 == 0);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &2, &4, &6]);
+
+        tape.expand();
+        assert_eq!(tape.peek_back(), Some(&8));
+    }
+
+    #[test]
+    fn folds_buffered() {
+        let mut tape = Tape::new(1..10);
+        tape.expand_n(4);
+        tape.pop_front();
+
+        assert_eq!(
+            tape.fold_buffered(Vec::new(), |mut acc, item| {
+                acc.push(*item);
+                acc
+            }),
+            vec![2, 3, 4]
+        );
+        assert_eq!(tape.sum_buffered::<i32>(), 9);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        let empty = Tape::new(0..0);
+        assert_eq!(empty.sum_buffered::<i32>(), 0);
+    }
+
+    #[test]
+    fn dedups_buffered() {
+        let mut tape = Tape::new([1, 1, 2, 2, 2, 3, 3].into_iter());
+        tape.expand_n(6);
+
+        tape.dedup_buffered();
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(tape.len(), 3);
+
+        // items expanded later are not deduplicated
+        tape.expand();
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &3]);
+    }
+
+    #[test]
+    fn inserts_at() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        assert_eq!(tape.insert_at(0, 10), None);
+        assert_eq!(tape.insert_at(2, 11), None);
+        assert_eq!(tape.insert_at(5, 12), None);
+        assert_eq!(
+            tape.iter().collect::<Vec<_>>(),
+            vec![&10, &0, &11, &1, &2, &12]
+        );
+        assert_eq!(tape.len(), 6);
+
+        tape.expand();
+        assert_eq!(tape.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn removes_at() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.remove_at(0), Some(0));
+        assert_eq!(tape.remove_at(1), Some(2));
+        assert_eq!(tape.remove_at(2), Some(4));
+        assert_eq!(tape.remove_at(2), None);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &3]);
+        assert_eq!(tape.len(), 2);
+    }
+
+    #[test]
+    fn drains_range() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.drain(1..3).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &3, &4]);
+
+        // the whole range is removed even if not consumed
+        drop(tape.drain(..2));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&4]);
+
+        tape.expand();
+        assert_eq!(tape.peek_back(), Some(&5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_at_out_of_bounds() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+        tape.insert_at(4, 42);
+    }
+
+    #[test]
+    fn swaps() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        tape.swap(0, 4);
+        assert_eq!(tape.peek_at(0), Some(&4));
+        assert_eq!(tape.peek_at(4), Some(&0));
+        assert_eq!(tape.peek_at(2), Some(&2));
+    }
+
+    #[test]
+    fn truncates() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        tape.truncate(7);
+        assert_eq!(tape.len(), 5);
+
+        tape.truncate(3);
+        assert_eq!(tape.len(), 3);
+        assert_eq!(tape.peek_back(), Some(&2));
+    }
+
+    #[test]
+    fn pops_front_if() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+
+        assert_eq!(tape.pop_front_if(|item| *item == 1), None);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+
+        assert_eq!(tape.pop_front_if(|item| *item == 0), Some(0));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2]);
+
+        tape.clear();
+        assert_eq!(tape.pop_front_if(|_| true), None);
+    }
+
+    #[test]
+    fn expand_n_reserves() {
+        let mut tape = Tape::new(0..10_000);
+        tape.expand();
+
+        // growing one by one would at least double the capacity past the needed size
+        tape.expand_n(5_000);
+        assert_eq!(tape.len(), 5_001);
+        assert!(tape.capacity() >= 5_001);
+        assert!(tape.capacity() < 2 * 5_001);
+
+        // does not reserve more than the iterator can produce
+        assert!(tape.expand_n(usize::MAX));
+        assert_eq!(tape.len(), 10_000);
+    }
+
+    #[test]
+    fn min_and_max_buffered() {
+        let mut tape = Tape::new([5, 2, 8, 2, 8, 1].into_iter());
+        assert_eq!(tape.min_buffered(), None);
+        assert_eq!(tape.max_buffered(), None);
+
+        tape.expand_n(5);
+        tape.pop_front();
+        assert_eq!(tape.min_buffered(), Some(&2));
+        assert_eq!(tape.max_buffered(), Some(&8));
+        assert_eq!(tape.len(), 4);
+
+        // first of equal items is returned
+        assert!(core::ptr::eq(tape.min_buffered().unwrap(), &tape[0]));
+        assert!(core::ptr::eq(tape.max_buffered().unwrap(), &tape[1]));
+    }
+
+    #[test]
+    fn counts_buffered() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape.count_buffered(|_| true), 0);
+
+        tape.expand_n(6);
+        assert_eq!(tape.count_buffered(|item| item % 2 == 0), 3);
+        assert_eq!(tape.count_buffered(|item| *item > 3), 2);
+        assert_eq!(tape.len(), 6);
+    }
+
+    #[test]
+    fn converts_into_band() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(2);
+
+        let mut band: Band<3, _> = tape.into_band();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&0, &1]);
+        assert!(band.expand());
+        assert_eq!(band.peek_back(), Some(&2));
+
+        // the oldest items are dropped if they do not fit
+        let mut tape = band.into_tape();
+        tape.expand_n(2);
+        assert!(!tape.expand_while(|item| *item > 5));
+
+        let mut band: Band<2, _> = tape.into_band();
+        assert_eq!(band.iter().collect::<Vec<_>>(), vec![&3, &4]);
+
+        // the item rejected by `expand_while` is not lost
+        assert!(band.expand());
+        assert_eq!(band.peek_back(), Some(&5));
+    }
+
+    #[test]
+    fn expands_step_by() {
+        let mut tape = Tape::new(0..20);
+
+        assert!(tape.expand_step_by(5, 2));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &2, &4, &6, &8]);
+
+        // continues right after the last kept item
+        assert!(tape.expand_step_by(2, 1));
+        assert_eq!(tape.peek_back(), Some(&10));
+
+        // stops when the iterator is exhausted
+        assert!(tape.expand_step_by(5, 5));
+        assert_eq!(tape.iter().skip(7).collect::<Vec<_>>(), vec![&11, &16]);
+        assert!(!tape.expand_step_by(1, 5));
+        assert!(!tape.expand_step_by(0, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn expand_step_by_zero() {
+        Tape::new(0..20).expand_step_by(5, 0);
+    }
+
+    #[test]
+    fn flattened_source() {
+        let mut tape = Tape::new(vec![vec![1, 2], vec![], vec![3]].into_iter().flatten());
+
+        assert!(tape.expand_n(2));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2]);
+
+        assert!(tape.expand_n(2));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert!(!tape.expand());
+    }
+
+    #[test]
+    fn debug_without_debug_iterator() {
+        struct Opaque(u8);
+
+        impl Iterator for Opaque {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0 = self.0.checked_add(1)?;
+                Some(self.0)
+            }
+        }
+
+        let mut tape = Tape::new(Opaque(0));
+        assert_eq!(format!("{tape:?}"), "Tape { items: [], .. }");
+
+        tape.expand_n(3);
+        tape.pop_front();
+        assert_eq!(format!("{tape:?}"), "Tape { items: [2, 3], .. }");
+    }
+
+    #[test]
+    fn swap_removes() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(5);
+
+        assert_eq!(tape.swap_remove(1), Some(1));
+        assert_eq!(tape.len(), 4);
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&0, &4, &2, &3]);
+
+        assert_eq!(tape.swap_remove(3), Some(3));
+        assert_eq!(tape.swap_remove(3), None);
+        assert_eq!(tape.len(), 3);
+    }
+
+    #[test]
+    fn eq_slices() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape, [] as [i32; 0]);
+
+        tape.expand_n(4);
+        tape.pop_front();
+        assert_eq!(tape, [1, 2, 3]);
+        assert_eq!(tape, &[1, 2, 3][..]);
+        assert_eq!(tape, vec![1, 2, 3]);
+
+        assert_ne!(tape, [1, 2]);
+        assert_ne!(tape, [1, 2, 4]);
+        assert_ne!(tape, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn gets_disjoint_mut() {
+        let mut tape = Tape::with_capacity(0..10, 4);
+        tape.expand_n(4);
+        tape.pop_front();
+        tape.expand();
+        tape.push_front(10);
+
+        let (a, b) = tape.get_disjoint_mut(4, 0).unwrap();
+        assert_eq!((*a, *b), (4, 10));
+        *a += 10;
+        *b += 10;
+
+        assert_eq!(tape.peek_at(0), Some(&20));
+        assert_eq!(tape.peek_at(4), Some(&14));
+
+        assert_eq!(tape.get_disjoint_mut(1, 1), None);
+        assert_eq!(tape.get_disjoint_mut(1, 5), None);
+        assert_eq!(tape.get_disjoint_mut(5, 1), None);
+    }
+
+    #[test]
+    fn expands_up_to_max() {
+        let mut tape = Tape::with_max(0.., 5);
+        assert_eq!(tape.max_len(), Some(5));
+
+        // halts on an infinite iterator
+        assert!(tape.expand_while(|_| true));
+        assert_eq!(tape.len(), 5);
+        assert!(!tape.expand_while(|_| true));
+        assert!(!tape.expand_n(3));
+        assert!(!tape.expand_step_by(2, 2));
+
+        // no items are lost while at the limit
+        tape.drain_front(2).for_each(drop);
+        assert!(tape.expand_n(3));
+        assert_eq!(tape.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5, &6]);
+
+        // items can still be added directly
+        tape.push_back(42);
+        assert_eq!(tape.len(), 6);
+
+        tape.set_max_len(None);
+        assert!(tape.expand());
+        assert_eq!(tape.peek_back(), Some(&7));
+    }
+
+    #[test]
+    fn expand_n_reserves_up_to_max() {
+        let mut tape = Tape::with_max(0u64..1_000_000_000, 4);
+
+        assert!(tape.expand_n(100_000_000));
+        assert_eq!(tape.len(), 4);
+        assert!(tape.capacity() < 16);
+    }
+
+    #[test]
+    fn clones_ends() {
+        let mut tape = Tape::new(["a", "b", "c"].into_iter().map(String::from));
+        assert_eq!(tape.front_cloned(), None);
+        assert_eq!(tape.back_cloned(), None);
+
+        tape.expand_n(3);
+        let mut front = tape.front_cloned().unwrap();
+        let back = tape.back_cloned().unwrap();
+
+        // the clones are independent of the items on the tape
+        front.push('!');
+        tape.pop_back();
+        assert_eq!(front, "a!");
+        assert_eq!(back, "c");
+        assert_eq!(tape.peek_front().map(String::as_str), Some("a"));
+        assert_eq!(tape.back_cloned().as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn rpositions() {
+        let mut tape = Tape::new([1, 2, 1, 2, 1].into_iter());
+        assert_eq!(tape.rposition(|_| true), None);
+
+        tape.expand_n(4);
+        assert_eq!(tape.position(|item| *item == 1), Some(0));
+        assert_eq!(tape.rposition(|item| *item == 1), Some(2));
+        assert_eq!(tape.rposition(|item| *item == 2), Some(3));
+        assert_eq!(tape.rposition(|item| *item == 3), None);
+    }
+
+    #[test]
+    fn is_full_only_at_max() {
+        let mut tape = Tape::new(0..100);
+        tape.expand_n(100);
+        assert!(!tape.is_full());
+
+        let mut tape = Tape::with_max(0..100, 3);
+        tape.expand_n(2);
+        assert!(!tape.is_full());
+        tape.expand();
+        assert!(tape.is_full());
+    }
+
+    #[test]
+    fn saturating_expands() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape.saturating_expand_n(4), 4);
+        assert_eq!(tape.saturating_expand_n(10), 6);
+        assert_eq!(tape.saturating_expand_n(10), 0);
+
+        let mut tape = Tape::with_max(0..10, 3);
+        assert_eq!(tape.saturating_expand_n(10), 3);
+        assert_eq!(tape.saturating_expand_n(10), 0);
+    }
+
+    #[test]
+    fn peeks_at_or_default() {
+        let mut tape = Tape::new(0..10);
+        assert_eq!(tape.peek_at_or(0, &-1), &-1);
+
+        tape.expand_n(3);
+        assert_eq!(tape.peek_at_or(0, &-1), &0);
+        assert_eq!(tape.peek_at_or(2, &-1), &2);
+        assert_eq!(tape.peek_at_or(3, &-1), &-1);
+    }
+
+    #[test]
+    fn iterates_buffered_then_source() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+        tape.push_front(42);
+        assert!(!tape.expand_while(|item| *item < 3));
+
+        // buffered items first, then the item rejected by `expand_while`, then the rest
+        assert_eq!(
+            tape.by_ref().take(6).collect::<Vec<_>>(),
+            vec![42, 0, 1, 2, 3, 4]
+        );
+        assert!(tape.is_empty());
+
+        tape.expand();
+        assert_eq!(tape.collect::<Vec<_>>(), vec![5, 6, 7, 8, 9]);
+
+        // a tape that must not expand does not yield items of the iterator
+        assert_eq!(Tape::with_max(0..10, 0).next(), None);
+    }
+
+    #[test]
+    fn seals() {
+        let mut tape = Tape::new(0..10);
+        tape.expand_n(3);
+        assert!(!tape.expand_while(|item| *item < 3));
+
+        tape.seal();
+        assert!(tape.source_exhausted());
+        assert!(!tape.expand());
+        assert!(!tape.expand_n(5));
+        assert_eq!(tape.progress(), None);
+
+        // buffered items remain accessible
+        assert_eq!(tape.peek_back(), Some(&2));
+        assert_eq!(tape.pop_front(), Some(0));
+        assert_eq!(tape.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
     #[test]
     fn is_iterator() {
         let mut tape = Tape::from(0..5);
@@ -0,0 +1,174 @@
+//! Borrowed, read-only views into the items found on a [`Ribbon`].
+//!
+//! [`Ribbon`]: crate::Ribbon
+
+use core::{iter::Chain, ops::Range, slice};
+
+/// A read-only view into a contiguous range of the items found on a [`Ribbon`], in logical order.
+/// It borrows the items, so it can be handed to helper functions without moving the `Ribbon`.
+///
+/// Created with [`Tape::view`] or [`Band::view`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use ribbon::{Ribbon, Tape};
+///
+/// let mut tape = Tape::new(0..10);
+/// tape.expand_n(6);
+///
+/// let view = tape.view(2..5);
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.peek_front(), Some(&2));
+/// assert_eq!(view.peek_back(), Some(&4));
+/// # }
+/// ```
+///
+/// [`Ribbon`]: crate::Ribbon
+/// [`Tape::view`]: crate::Tape::view
+/// [`Band::view`]: crate::Band::view
+#[derive(Debug)]
+pub struct RibbonView<'a, T> {
+    first: &'a [T],
+    second: &'a [T],
+}
+
+impl<'a, T> RibbonView<'a, T> {
+    /// Creates a view into the given range of the items held by the pair of slices, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub(crate) fn new(first: &'a [T], second: &'a [T], range: Range<usize>) -> Self {
+        let Range { start, end } = range;
+        let len = first.len() + second.len();
+
+        assert!(start <= end, "view starts at {start} but ends at {end}");
+        assert!(
+            end <= len,
+            "range end index {end} out of range for ribbon of length {len}"
+        );
+
+        let split = first.len();
+
+        if end <= split {
+            RibbonView {
+                first: &first[start..end],
+                second: &[],
+            }
+        } else if start >= split {
+            RibbonView {
+                first: &second[start - split..end - split],
+                second: &[],
+            }
+        } else {
+            RibbonView {
+                first: &first[start..],
+                second: &second[..end - split],
+            }
+        }
+    }
+
+    /// Returns a reference to the first item in the view if it exists. Returns `None` otherwise.
+    pub fn peek_front(&self) -> Option<&'a T> {
+        self.peek_at(0)
+    }
+
+    /// Returns a reference to the last item in the view if it exists. Returns `None` otherwise.
+    pub fn peek_back(&self) -> Option<&'a T> {
+        self.second.last().or(self.first.last())
+    }
+
+    /// Returns a reference to the item at the given index of the view if it exists. Returns `None`
+    /// if index out of bounds.
+    pub fn peek_at(&self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.first.len()) {
+            None => self.first.get(index),
+            Some(index) => self.second.get(index),
+        }
+    }
+
+    /// Returns an iterator over references to the items in the view, in order.
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.first.iter().chain(self.second)
+    }
+
+    /// Returns a view into the given range of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn view(&self, range: Range<usize>) -> RibbonView<'a, T> {
+        RibbonView::new(self.first, self.second, range)
+    }
+
+    /// Returns a pair of slices which contain, in order, the items in the view.
+    pub fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.first, self.second)
+    }
+
+    /// Returns the number of items in the view.
+    pub fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+
+    /// Returns `true` if the view holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for RibbonView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RibbonView<'_, T> {}
+
+impl<'a, T> IntoIterator for RibbonView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RibbonView;
+
+    #[test]
+    fn views_across_slices() {
+        let (first, second) = ([0, 1, 2, 3], [4, 5, 6]);
+
+        let view = RibbonView::new(&first, &second, 2..5);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.peek_front(), Some(&2));
+        assert_eq!(view.peek_at(2), Some(&4));
+        assert_eq!(view.peek_at(3), None);
+        assert_eq!(view.peek_back(), Some(&4));
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        let view = RibbonView::new(&first, &second, 5..7);
+        assert_eq!(view.as_slices(), (&[5, 6][..], &[][..]));
+
+        let view = RibbonView::new(&first, &second, 1..3);
+        assert_eq!(view.as_slices(), (&[1, 2][..], &[][..]));
+        assert_eq!(view.view(1..2).peek_front(), Some(&2));
+
+        let view = RibbonView::new(&first, &second, 3..3);
+        assert!(view.is_empty());
+        assert_eq!(view.peek_front(), None);
+        assert_eq!(view.peek_back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_out_of_bounds() {
+        let (first, second) = ([0, 1], [2]);
+        RibbonView::new(&first, &second, 1..4);
+    }
+}
@@ -1,5 +1,6 @@
 use ribbon::Ribbon;
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_tape() {
     use ribbon::Tape;
@@ -32,6 +33,7 @@ fn test_band() {
     assert_eq!(band.progress(), None);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_enroll() {
     use ribbon::Enroll;
@@ -50,3 +52,54 @@ fn test_enroll() {
     assert_eq!(band.progress(), Some(0));
     assert_eq!(band.progress(), Some(1));
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_enroll_into() {
+    use ribbon::EnrollInto;
+    use std::collections::HashMap;
+
+    let mut tape = vec![1, 2, 3].into_tape();
+    tape.expand_n(3);
+    assert_eq!(tape.peek_at(1), Some(&2));
+
+    let mut band = [1, 2, 3].into_band::<2>();
+    band.expand_n(3);
+    assert_eq!(band.peek_front(), Some(&2));
+    assert_eq!(band.peek_back(), Some(&3));
+
+    let map = HashMap::from([("a", 1), ("b", 2)]);
+    let mut tape = map.into_tape();
+    tape.expand_n(5);
+    assert_eq!(tape.len(), 2);
+    assert!(tape.iter().any(|(key, value)| *key == "b" && *value == 2));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_dyn_ribbon() {
+    use ribbon::{Band, DynBand, DynRibbon, Tape};
+
+    let mut ribbons: Vec<DynRibbon<u32>> = vec![
+        Box::new(Tape::new(0..10)),
+        Box::new(Band::<3, _>::new(0..10)),
+        Box::new(DynBand::new(0..10, 3)),
+    ];
+
+    for ribbon in &mut ribbons {
+        ribbon.expand_n(3);
+
+        assert_eq!(ribbon.progress(), Some(0));
+        assert_eq!(ribbon.peek_front(), Some(&1));
+        assert_eq!(ribbon.peek_back(), Some(&3));
+        assert_eq!(ribbon.len(), 3);
+    }
+
+    // concrete ribbons can be compared with trait objects
+    let mut tape = Tape::new(1..4);
+    tape.expand_n(3);
+    assert!(ribbons.iter().all(|ribbon| tape.content_eq(&**ribbon)));
+
+    assert_eq!(ribbons[0].pop_back(), Some(3));
+    assert!(!tape.content_eq(&*ribbons[0]));
+}
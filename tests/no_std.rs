@@ -0,0 +1,18 @@
+#![no_std]
+
+use ribbon::{Band, Enroll, Ribbon};
+
+#[test]
+fn test_band_no_std() {
+    let mut band: Band<3, _> = Band::new(0..10);
+    band.expand_n(3);
+
+    assert_eq!(band.len(), 3);
+    assert_eq!(band.progress(), Some(0));
+    assert_eq!(band.peek_front(), Some(&1));
+    assert_eq!(band.peek_back(), Some(&3));
+
+    let mut band = (0..10).band::<2>();
+    assert!(band.expand());
+    assert_eq!(band.pop_front(), Some(0));
+}